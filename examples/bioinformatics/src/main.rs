@@ -41,6 +41,7 @@ fn main() {
     // --- Simulation State ---
     let mut results = Vec::new();
     let mut signature_history: VecDeque<u64> = VecDeque::with_capacity(entropy_window_size);
+    let mut entropy_calculator = Entropy::new();
     let mut mutation_events = 0;
     let mut chaotic_events = 0;
 
@@ -62,15 +63,14 @@ fn main() {
             results.push((p, signature));
 
             // --- Entropy Pulse Check ---
-            // Add the new signature to our history for entropy calculation.
+            // Slide the window: evict the oldest signature and add the new one.
             if signature_history.len() >= entropy_window_size {
-                signature_history.pop_front(); // Keep the window size fixed.
+                if let Some(evicted) = signature_history.pop_front() {
+                    entropy_calculator.remove(&evicted);
+                }
             }
             signature_history.push_back(signature);
-
-            // Recalculate entropy with the new history.
-            let mut entropy_calculator = Entropy::new();
-            entropy_calculator.add_all(signature_history.iter().copied());
+            entropy_calculator.add(signature);
             let current_entropy = entropy_calculator.total_entropy();
 
             // Check if the new state triggers an entropy pulse.
@@ -8,7 +8,8 @@
 //! `CompositeMass`—feeds them the same sequence of primes, and then compares
 //! their final "drift magnitude" scores.
 
-use moma::origin_drift::OriginDrift;
+use moma::core::OriginStrategy;
+use moma::origin_drift::{self, OriginDrift};
 use moma::primes;
 use moma::strategy;
 
@@ -59,12 +60,47 @@ fn main() {
         "  - CompositeMass Strategy Volatility: {:.2}",
         mass_drift_magnitude
     );
+    println!("\nVolatility Profiles (variance / largest jump):");
+    println!(
+        "  - PrimeGap:      variance {:>8.2} | max delta {}",
+        gap_drift.drift_variance(),
+        gap_drift.max_delta().unwrap_or(0)
+    );
+    println!(
+        "  - CompositeMass: variance {:>8.2} | max delta {}",
+        mass_drift.drift_variance(),
+        mass_drift.max_delta().unwrap_or(0)
+    );
     println!("-------------------------------------------------");
 
-    // 4. Compare the results to determine which strategy was more volatile.
-    if mass_drift_magnitude > gap_drift_magnitude {
-        println!("\n🏆 Conclusion: The CompositeMass strategy was more volatile.");
-    } else {
-        println!("\n🏆 Conclusion: The PrimeGap strategy was more volatile.");
+    // 4. Rank the strategies to determine which one was most volatile.
+    let tested_primes: Vec<u64> = primes::iter_from(3).take(num_primes_to_test).collect();
+    let strategies: Vec<(&str, Box<dyn OriginStrategy>)> = vec![
+        ("PrimeGap", Box::new(strategy::PrimeGap)),
+        ("CompositeMass", Box::new(strategy::CompositeMass)),
+        ("DivisorSum", Box::new(strategy::DivisorSum)),
+    ];
+    let ranking = origin_drift::rank_strategies(modulus, &tested_primes, strategies);
+    if let Some((most_volatile, _)) = ranking.first() {
+        println!("\n🏆 Conclusion: The {} strategy was most volatile.", most_volatile);
+    }
+
+    // 5. Sweep affine coefficients over the PrimeGap strategy to see how
+    //    scaling and shifting the origin changes its volatility.
+    println!("\nAffine sweep over PrimeGap (origin = mul * gap + add):");
+    for (mul, add) in [(1, 0), (2, 0), (3, 1), (7, 5)] {
+        let affine = strategy::Affine { inner: strategy::PrimeGap, mul, add };
+        let mut drift = OriginDrift::new(modulus, affine);
+        let mut p = 3;
+        for _ in 0..num_primes_to_test {
+            drift.next(p);
+            p = primes::next_prime(p);
+        }
+        println!(
+            "  - mul={:<2} add={:<2} | Volatility: {:.2}",
+            mul,
+            add,
+            drift.drift_magnitude()
+        );
     }
 }
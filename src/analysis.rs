@@ -46,3 +46,42 @@ impl CompositeDampener {
     }
 }
 
+/// A strategy for attenuating a value according to the structure of an integer.
+///
+/// Not to be confused with `CompositeDampener`, which scores a whole range rather
+/// than dampening individual values.
+pub trait DampenStrategy {
+    /// Returns `value` attenuated according to the integer `n`.
+    fn dampen(&self, n: u64, value: f64) -> f64;
+}
+
+/// Dampens a value by the prime factor mass of `n`.
+///
+/// The value is divided by `primes::prime_factor_mass(n)`, so primes (and 0 and 1,
+/// whose mass is clamped to 1) pass through unchanged while highly composite
+/// numbers are dampened the most.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct MassDampener;
+
+impl DampenStrategy for MassDampener {
+    fn dampen(&self, n: u64, value: f64) -> f64 {
+        value / primes::prime_factor_mass(n).max(1) as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mass_dampener() {
+        let dampener = MassDampener;
+        assert_eq!(dampener.dampen(13, 6.0), 6.0);
+        assert_eq!(dampener.dampen(12, 6.0), 2.0); // 12 = 2^2 * 3 has mass 3.
+        assert_eq!(dampener.dampen(64, 6.0), 1.0);
+        assert_eq!(dampener.dampen(1, 6.0), 6.0);
+
+        let strategy: &dyn DampenStrategy = &dampener;
+        assert_eq!(strategy.dampen(10, 1.0), 0.5);
+    }
+}
@@ -1,6 +1,9 @@
 //! This module provides functionality to handle barycentric coordinates
 
-#[derive(Debug, Clone, Copy)]
+use std::ops::{Add, Sub};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct OriginShift {
     pub dx: f64,
     pub dy: f64,
@@ -14,4 +17,221 @@ impl OriginShift {
     pub fn magnitude(&self) -> f64 {
         (self.dx.powi(2) + self.dy.powi(2)).sqrt()
     }
-}
\ No newline at end of file
+
+    /// Returns the direction of the shift in radians, `atan2(dy, dx)`, in `[-π, π]`.
+    ///
+    /// A sequence of angles can be passed straight to `entropy::calculate_path_entropy`.
+    pub fn angle(&self) -> f64 {
+        self.dy.atan2(self.dx)
+    }
+
+    /// Returns the shift with both components multiplied by `f`.
+    pub fn scale(&self, f: f64) -> OriginShift {
+        OriginShift { dx: self.dx * f, dy: self.dy * f }
+    }
+}
+
+impl Add for OriginShift {
+    type Output = OriginShift;
+
+    fn add(self, other: OriginShift) -> OriginShift {
+        OriginShift { dx: self.dx + other.dx, dy: self.dy + other.dy }
+    }
+}
+
+impl Sub for OriginShift {
+    type Output = OriginShift;
+
+    fn sub(self, other: OriginShift) -> OriginShift {
+        OriginShift { dx: self.dx - other.dx, dy: self.dy - other.dy }
+    }
+}
+
+/// An origin shift in any number of dimensions, e.g. 3D for an orbital simulation.
+///
+/// The 2D `OriginShift` remains the lightweight `Copy` type for planar work; this
+/// version stores its components in a `Vec`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OriginShiftN {
+    pub coords: Vec<f64>,
+}
+
+impl OriginShiftN {
+    /// Returns the zero shift in `dim` dimensions.
+    pub fn zero(dim: usize) -> Self {
+        OriginShiftN { coords: vec![0.0; dim] }
+    }
+
+    /// Returns the number of dimensions.
+    pub fn dim(&self) -> usize {
+        self.coords.len()
+    }
+
+    /// Returns the Euclidean norm over all components.
+    pub fn magnitude(&self) -> f64 {
+        self.coords.iter().map(|c| c.powi(2)).sum::<f64>().sqrt()
+    }
+
+    /// Returns the shift with every component multiplied by `f`.
+    pub fn scale(&self, f: f64) -> OriginShiftN {
+        OriginShiftN { coords: self.coords.iter().map(|c| c * f).collect() }
+    }
+
+    /// Applies `op` componentwise, checking that the dimensions match.
+    fn zip_with(self, other: OriginShiftN, op: impl Fn(f64, f64) -> f64) -> OriginShiftN {
+        assert_eq!(
+            self.dim(),
+            other.dim(),
+            "Cannot combine origin shifts of different dimensions."
+        );
+        OriginShiftN {
+            coords: self.coords.iter().zip(&other.coords).map(|(&a, &b)| op(a, b)).collect(),
+        }
+    }
+}
+
+impl From<OriginShift> for OriginShiftN {
+    fn from(shift: OriginShift) -> Self {
+        OriginShiftN { coords: vec![shift.dx, shift.dy] }
+    }
+}
+
+impl Add for OriginShiftN {
+    type Output = OriginShiftN;
+
+    /// Adds the shifts componentwise.
+    ///
+    /// # Panics
+    /// Panics if the shifts have different dimensions.
+    fn add(self, other: OriginShiftN) -> OriginShiftN {
+        self.zip_with(other, |a, b| a + b)
+    }
+}
+
+impl Sub for OriginShiftN {
+    type Output = OriginShiftN;
+
+    /// Subtracts the shifts componentwise.
+    ///
+    /// # Panics
+    /// Panics if the shifts have different dimensions.
+    fn sub(self, other: OriginShiftN) -> OriginShiftN {
+        self.zip_with(other, |a, b| a - b)
+    }
+}
+
+/// An integer origin on a 2D lattice, the discrete counterpart of `OriginShift`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct LatticeOrigin {
+    pub dx: i64,
+    pub dy: i64,
+}
+
+impl LatticeOrigin {
+    /// Rounds a continuous `OriginShift` to the nearest lattice point.
+    /// Components outside the `i64` range saturate, and NaN maps to 0.
+    pub fn from_shift(shift: &OriginShift) -> Self {
+        LatticeOrigin {
+            dx: shift.dx.round() as i64,
+            dy: shift.dy.round() as i64,
+        }
+    }
+}
+
+/// Moving-origin modular arithmetic on a 2D integer grid.
+///
+/// Each axis is reduced independently: `x` is shifted by the origin's `dx` and taken
+/// modulo `modulus_x`, and likewise for `y`. This maps 2D positions, such as rounded
+/// barycenter coordinates, onto a modular (toroidal) grid.
+#[derive(Debug, Clone, Copy)]
+pub struct Moma2DRing {
+    pub modulus_x: u64,
+    pub modulus_y: u64,
+}
+
+impl Moma2DRing {
+    /// Creates a new `Moma2DRing` with a modulus for each axis.
+    pub fn new(modulus_x: u64, modulus_y: u64) -> Self {
+        Self { modulus_x, modulus_y }
+    }
+
+    /// Calculates the residue of the point `(x, y)` after shifting it by `origin`.
+    ///
+    /// Returns `((x + dx) mod modulus_x, (y + dy) mod modulus_y)`, always in the
+    /// non-negative range even for negative origins. As with `MomaRing::residue`,
+    /// an axis with a modulus of zero is not reduced, and its shift wraps around `u64`.
+    pub fn residue(&self, x: u64, y: u64, origin: LatticeOrigin) -> (u64, u64) {
+        (
+            Self::shift_axis(x, origin.dx, self.modulus_x),
+            Self::shift_axis(y, origin.dy, self.modulus_y),
+        )
+    }
+
+    fn shift_axis(value: u64, shift: i64, modulus: u64) -> u64 {
+        if modulus == 0 {
+            return value.wrapping_add_signed(shift);
+        }
+        (value as i128 + shift as i128).rem_euclid(modulus as i128) as u64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_origin_shift_n() {
+        let shift = OriginShiftN { coords: vec![1.0, 2.0, 2.0] };
+        assert_eq!(shift.magnitude(), 3.0);
+        assert_eq!(OriginShiftN::zero(3).magnitude(), 0.0);
+        assert_eq!(OriginShiftN::zero(4).dim(), 4);
+
+        let other = OriginShiftN { coords: vec![0.5, -1.0, 4.0] };
+        assert_eq!((shift.clone() + other.clone()).coords, vec![1.5, 1.0, 6.0]);
+        assert_eq!((shift.clone() - other).coords, vec![0.5, 3.0, -2.0]);
+        assert_eq!(shift.scale(2.0).magnitude(), 6.0);
+
+        let planar = OriginShift { dx: 3.0, dy: 4.0 };
+        assert_eq!(OriginShiftN::from(planar).magnitude(), planar.magnitude());
+    }
+
+    #[test]
+    #[should_panic(expected = "different dimensions")]
+    fn test_origin_shift_n_dimension_mismatch() {
+        let _ = OriginShiftN::zero(2) + OriginShiftN::zero(3);
+    }
+
+    #[test]
+    fn test_moma_2d_ring() {
+        let ring = Moma2DRing::new(10, 7);
+        let origin = LatticeOrigin { dx: 3, dy: -2 };
+        assert_eq!(ring.residue(8, 1, origin), (1, 6));
+        assert_eq!(ring.residue(0, 0, LatticeOrigin::default()), (0, 0));
+        // 2^64 = 6 (mod 10) and 9 - 30 = -21 = 0 (mod 7).
+        assert_eq!(ring.residue(u64::MAX, 9, LatticeOrigin { dx: 1, dy: -30 }), (6, 0));
+
+        let shift = OriginShift { dx: 2.6, dy: -0.4 };
+        assert_eq!(LatticeOrigin::from_shift(&shift), LatticeOrigin { dx: 3, dy: 0 });
+
+        let unreduced = Moma2DRing::new(0, 5);
+        assert_eq!(unreduced.residue(4, 4, LatticeOrigin { dx: -5, dy: 2 }), (u64::MAX, 1));
+    }
+
+    #[test]
+    fn test_origin_shift_vector_ops() {
+        let up = OriginShift { dx: 0.0, dy: 1.0 };
+        assert!((up.angle() - std::f64::consts::FRAC_PI_2).abs() < 1e-12);
+        assert_eq!(OriginShift { dx: -1.0, dy: 0.0 }.angle(), std::f64::consts::PI);
+
+        let a = OriginShift { dx: 1.5, dy: -2.0 };
+        let b = OriginShift { dx: 0.5, dy: 4.0 };
+        assert_eq!(a + b, OriginShift { dx: 2.0, dy: 2.0 });
+        assert_eq!(a - b, OriginShift { dx: 1.0, dy: -6.0 });
+        assert_eq!(a.scale(2.0), OriginShift { dx: 3.0, dy: -4.0 });
+        assert_eq!((a + OriginShift::zero()).magnitude(), 2.5);
+
+        let angles: Vec<f64> = [up, up, a].iter().map(OriginShift::angle).collect();
+        assert!(crate::entropy::calculate_path_entropy(angles) > 0.0);
+    }
+}
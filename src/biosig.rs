@@ -2,11 +2,48 @@
 // This module defines a "biological signature" by mapping MOMA's numeric
 // output to the effects of genetic mutations.
 
-use crate::codon::CodonTable;
+use crate::codon::{reverse_complement, AminoAcid, CodonTable, GeneticCode};
 use crate::core::{MomaRing, OriginStrategy};
 use crate::mutation::Mutation;
+use std::fmt;
 use std::marker::PhantomData;
 
+/// A function pointer type that defines a point-mutation rule: it maps a DNA base
+/// to the base it mutates into, or `None` if the base cannot be mutated.
+pub type BaseCycleFn = fn(char) -> Option<char>;
+
+/// The reason a `BioSigAnalyzer` could not analyze a sequence.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BioSigError {
+    /// The sequence is empty, or ends before the codon containing the mutation site.
+    SequenceTooShort,
+    /// The mutation site precedes the first complete codon of the reading frame.
+    SiteBeforeFrame,
+    /// The codon at the mutation site contains a character that is not an uppercase
+    /// base (`A`, `C`, `G`, `T` or `U`), or the mutation rule cannot mutate this base.
+    InvalidBase(char),
+    /// The original or mutated codon has no entry in the codon table. The built-in
+    /// genetic codes cover all 64 codons, so valid input never produces this.
+    UntranslatableCodon(String),
+}
+
+impl fmt::Display for BioSigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BioSigError::SequenceTooShort => {
+                write!(f, "sequence is too short to hold a codon at the mutation site")
+            }
+            BioSigError::SiteBeforeFrame => {
+                write!(f, "mutation site precedes the first codon of the reading frame")
+            }
+            BioSigError::InvalidBase(base) => write!(f, "invalid or unmutable base {base:?}"),
+            BioSigError::UntranslatableCodon(codon) => write!(f, "codon {codon:?} cannot be translated"),
+        }
+    }
+}
+
+impl std::error::Error for BioSigError {}
+
 /// An analyzer that generates MOMA signatures and interprets them as genetic mutations.
 ///
 /// It uses a `MomaRing` to generate a numeric signature from a prime number, then
@@ -15,6 +52,8 @@ use std::marker::PhantomData;
 pub struct BioSigAnalyzer<S: OriginStrategy> {
     ring: MomaRing<S>,
     codon_table: CodonTable,
+    frame: usize,
+    base_cycle: BaseCycleFn,
     _strategy: PhantomData<S>,
 }
 
@@ -25,56 +64,139 @@ impl<S: OriginStrategy> BioSigAnalyzer<S> {
     /// * `modulus` - The modulus for the internal `MomaRing`.
     /// * `strategy` - The `OriginStrategy` to use for generating signatures.
     pub fn new(modulus: u64, strategy: S) -> Self {
+        Self::with_code(modulus, strategy, GeneticCode::Standard)
+    }
+
+    /// Creates a new `BioSigAnalyzer` that translates codons using a chosen genetic code.
+    ///
+    /// # Arguments
+    /// * `modulus` - The modulus for the internal `MomaRing`.
+    /// * `strategy` - The `OriginStrategy` to use for generating signatures.
+    /// * `code` - The `GeneticCode` used to translate original and mutated codons.
+    pub fn with_code(modulus: u64, strategy: S, code: GeneticCode) -> Self {
         Self {
             ring: MomaRing::new(modulus, strategy),
-            codon_table: CodonTable::new(),
+            codon_table: CodonTable::with_code(code),
+            frame: 0,
+            base_cycle: cycle_base,
             _strategy: PhantomData,
         }
     }
 
+    /// Returns a `BioSigAnalyzerBuilder` for configuring every aspect of the analyzer.
+    pub fn builder() -> BioSigAnalyzerBuilder<S> {
+        BioSigAnalyzerBuilder::default()
+    }
+
     /// Generates a MOMA signature for a prime and analyzes its mutational effect.
     ///
     /// # Arguments
     /// * `p` - The prime number to use as the context for the MOMA signature.
     /// * `dna_sequence` - The DNA sequence to apply the simulated mutation to.
     ///
+    /// Codons are read in the analyzer's configured reading frame, which is frame 0
+    /// unless set with `BioSigAnalyzerBuilder::frame`.
+    ///
     /// # Returns
     /// An `Option<(u64, Mutation)>` containing the numeric signature and the
     /// resulting `Mutation` analysis. Returns `None` if the sequence is too short
-    /// or the signature points to an invalid position.
+    /// or the signature points to an invalid position; use `try_analyze` to find
+    /// out why.
     pub fn analyze(&self, p: u64, dna_sequence: &str) -> Option<(u64, Mutation)> {
+        self.try_analyze(p, dna_sequence).ok()
+    }
+
+    /// Like `analyze`, but reports why a sequence could not be analyzed.
+    ///
+    /// # Errors
+    /// See `try_analyze_frame`.
+    pub fn try_analyze(&self, p: u64, dna_sequence: &str) -> Result<(u64, Mutation), BioSigError> {
+        self.try_analyze_frame(p, dna_sequence, self.frame)
+    }
+
+    /// Like `analyze`, but reads codons in the given forward reading frame.
+    ///
+    /// Frame `f` places codon boundaries at positions `f, f + 3, f + 6, ...`, so the
+    /// same signature-derived mutation site can fall in a different codon per frame.
+    ///
+    /// # Arguments
+    /// * `p` - The prime number to use as the context for the MOMA signature.
+    /// * `dna_sequence` - The DNA sequence to apply the simulated mutation to.
+    /// * `frame` - The reading frame offset: 0, 1, or 2.
+    ///
+    /// # Returns
+    /// As for `analyze`. Also returns `None` if the mutation site lies outside a full
+    /// codon of the chosen frame (before its first or after its last complete codon).
+    ///
+    /// # Panics
+    /// Panics if `frame > 2`.
+    pub fn analyze_frame(&self, p: u64, dna_sequence: &str, frame: usize) -> Option<(u64, Mutation)> {
+        self.try_analyze_frame(p, dna_sequence, frame).ok()
+    }
+
+    /// Like `analyze_frame`, but reports why a sequence could not be analyzed.
+    ///
+    /// # Errors
+    /// - `InvalidBase` if the codon at the mutation site contains anything but
+    ///   uppercase `A`, `C`, `G`, `T` or `U`, or the mutation rule cannot mutate the
+    ///   base at the site. The rest of the sequence is not checked.
+    /// - `SequenceTooShort` if the sequence is empty or ends before a full codon at
+    ///   the mutation site.
+    /// - `SiteBeforeFrame` if the site precedes the frame's first complete codon.
+    /// - `UntranslatableCodon` if the codon table has no entry for the original or
+    ///   mutated codon.
+    ///
+    /// # Panics
+    /// Panics if `frame > 2`.
+    pub fn try_analyze_frame(
+        &self,
+        p: u64,
+        dna_sequence: &str,
+        frame: usize,
+    ) -> Result<(u64, Mutation), BioSigError> {
+        assert!(frame <= 2, "Reading frame must be 0, 1, or 2.");
+        if dna_sequence.is_empty() {
+            return Err(BioSigError::SequenceTooShort);
+        }
+
         // 1. Generate the core MOMA signature.
         let signature = self.ring.signature(p);
 
         // 2. Use the signature to determine the mutation site.
         let mutation_pos = (signature % dna_sequence.len() as u64) as usize;
 
-        // 3. Determine the codon affected by the mutation.
-        let codon_start = (mutation_pos / 3) * 3;
+        // 3. Determine the codon affected by the mutation within the chosen frame.
+        if mutation_pos < frame {
+            return Err(BioSigError::SiteBeforeFrame);
+        }
+        let codon_start = frame + ((mutation_pos - frame) / 3) * 3;
         if codon_start + 3 > dna_sequence.len() {
-            return None; // Not enough sequence left for a full codon.
+            return Err(BioSigError::SequenceTooShort); // Not enough sequence left for a full codon.
+        }
+        // Only the codon being read must be valid. Positions are byte offsets, so this
+        // also keeps the slicing below on character boundaries.
+        let invalid = dna_sequence
+            .char_indices()
+            .skip_while(|&(i, c)| i + c.len_utf8() <= codon_start)
+            .take_while(|&(i, _)| i < codon_start + 3)
+            .find(|&(_, c)| !"ACGTU".contains(c));
+        if let Some((_, base)) = invalid {
+            return Err(BioSigError::InvalidBase(base));
         }
         let original_codon_str = &dna_sequence[codon_start..codon_start + 3];
 
         // 4. Translate the original codon.
-        let original_aa = self.codon_table.translate(original_codon_str)?;
+        let original_aa = self.translate(original_codon_str)?;
 
         // 5. Simulate the mutation by changing the base at the mutation position.
         let mut mutated_sequence = dna_sequence.to_string();
-        let original_char = mutated_sequence.chars().nth(mutation_pos).unwrap();
-        // Simple mutation: cycle through A -> C -> G -> T -> A
-        let new_char = match original_char {
-            'A' => 'C',
-            'C' => 'G',
-            'G' => 'T',
-            'T' => 'A',
-            _ => return None, // Invalid character in sequence
-        };
+        let original_char = dna_sequence.as_bytes()[mutation_pos] as char;
+        let new_char = (self.base_cycle)(original_char).ok_or(BioSigError::InvalidBase(original_char))?;
         mutated_sequence.replace_range(mutation_pos..mutation_pos + 1, &new_char.to_string());
 
         // 6. Analyze the new, mutated codon.
         let mutated_codon_str = &mutated_sequence[codon_start..codon_start + 3];
-        let mutated_aa = self.codon_table.translate(mutated_codon_str)?;
+        let mutated_aa = self.translate(mutated_codon_str)?;
 
         // 7. Create and return the final analysis.
         let mutation = Mutation::new(
@@ -84,6 +206,396 @@ impl<S: OriginStrategy> BioSigAnalyzer<S> {
             mutated_aa,
         );
 
+        Ok((signature, mutation))
+    }
+
+    /// Like `analyze_frame`, but analyzes the reverse (complementary) strand.
+    ///
+    /// The sequence is reverse-complemented first, so `frame` and the mutation site are
+    /// measured from the 5' end of the reverse strand. The returned codons belong to
+    /// that strand.
+    ///
+    /// # Returns
+    /// As for `analyze_frame`. Also returns `None` if the sequence contains bases that
+    /// cannot be complemented.
+    ///
+    /// # Panics
+    /// Panics if `frame > 2`.
+    pub fn analyze_reverse(&self, p: u64, dna_sequence: &str, frame: usize) -> Option<(u64, Mutation)> {
+        let reverse_strand = reverse_complement(dna_sequence)?;
+        self.analyze_frame(p, &reverse_strand, frame)
+    }
+
+    /// Generates a MOMA signature for a prime and analyzes the effect of a single-base
+    /// insertion or deletion at the signature-derived position.
+    ///
+    /// For an insertion, the base at the mutation site is mutated (as in `analyze`) and
+    /// the new base is inserted before it; for a deletion, the base is removed. The
+    /// codon at the edit site, in the analyzer's configured reading frame, is then
+    /// re-read in the shifted sequence. The result is
+    /// classified as `Frameshift` when the edited length is not a multiple of 3;
+    /// otherwise the reading frame is preserved and the usual substitution classes apply.
+    ///
+    /// # Arguments
+    /// * `p` - The prime number to use as the context for the MOMA signature.
+    /// * `dna_sequence` - The DNA sequence to edit.
+    /// * `insert` - `true` to insert a base, `false` to delete one.
+    ///
+    /// # Returns
+    /// An `Option<(u64, Mutation)>` with the signature and the analysis. Returns `None`
+    /// if either sequence is too short at the edit site, if the site precedes the first
+    /// codon of the reading frame, or on invalid bases or codons.
+    pub fn analyze_indel(&self, p: u64, dna_sequence: &str, insert: bool) -> Option<(u64, Mutation)> {
+        if dna_sequence.is_empty() {
+            return None;
+        }
+        let signature = self.ring.signature(p);
+        let mutation_pos = (signature % dna_sequence.len() as u64) as usize;
+        if mutation_pos < self.frame {
+            return None;
+        }
+        let codon_start = self.frame + ((mutation_pos - self.frame) / 3) * 3;
+        if codon_start + 3 > dna_sequence.len() {
+            return None;
+        }
+        // Positions are byte offsets: `get` rejects codons that split a character, and a
+        // codon that translates is ASCII, so the edit site is a character boundary.
+        let original_codon_str = dna_sequence.get(codon_start..codon_start + 3)?;
+        let original_aa = self.codon_table.translate(original_codon_str)?;
+
+        let mut edited_sequence = dna_sequence.to_string();
+        let original_char = dna_sequence.as_bytes()[mutation_pos] as char;
+        let new_char = (self.base_cycle)(original_char)?;
+        if insert {
+            edited_sequence.insert(mutation_pos, new_char);
+        } else {
+            edited_sequence.remove(mutation_pos);
+        }
+        if codon_start + 3 > edited_sequence.len() {
+            return None;
+        }
+
+        let mutated_codon_str = edited_sequence.get(codon_start..codon_start + 3)?;
+        let mutated_aa = self.codon_table.translate(mutated_codon_str)?;
+
+        let original_codon = original_codon_str.to_string();
+        let mutated_codon = mutated_codon_str.to_string();
+        let mutation = if edited_sequence.len().is_multiple_of(3) {
+            Mutation::new(original_codon, mutated_codon, original_aa, mutated_aa)
+        } else {
+            Mutation::frameshift(original_codon, mutated_codon, original_aa, mutated_aa)
+        };
+
         Some((signature, mutation))
     }
-}
\ No newline at end of file
+
+    /// Private helper translating a codon, naming it in the error on failure.
+    fn translate(&self, codon: &str) -> Result<AminoAcid, BioSigError> {
+        self.codon_table
+            .translate(codon)
+            .ok_or_else(|| BioSigError::UntranslatableCodon(codon.to_string()))
+    }
+}
+
+/// A builder for a `BioSigAnalyzer` with a custom genetic code, reading frame, or
+/// point-mutation rule.
+///
+/// The modulus and strategy are required; everything else defaults to the
+/// behavior of `BioSigAnalyzer::new` (standard code, frame 0, A -> C -> G -> T -> A).
+///
+/// ```
+/// use moma::biosig::BioSigAnalyzer;
+/// use moma::codon::GeneticCode;
+/// use moma::strategy::PrimeGap;
+///
+/// let analyzer = BioSigAnalyzer::builder()
+///     .modulus(1000)
+///     .strategy(PrimeGap)
+///     .genetic_code(GeneticCode::VertebrateMito)
+///     .frame(1)
+///     .build();
+/// ```
+pub struct BioSigAnalyzerBuilder<S: OriginStrategy> {
+    modulus: Option<u64>,
+    strategy: Option<S>,
+    code: GeneticCode,
+    frame: usize,
+    base_cycle: BaseCycleFn,
+}
+
+impl<S: OriginStrategy> Default for BioSigAnalyzerBuilder<S> {
+    fn default() -> Self {
+        Self {
+            modulus: None,
+            strategy: None,
+            code: GeneticCode::Standard,
+            frame: 0,
+            base_cycle: cycle_base,
+        }
+    }
+}
+
+impl<S: OriginStrategy> BioSigAnalyzerBuilder<S> {
+    /// Creates a builder with default settings and no modulus or strategy.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the modulus for the internal `MomaRing`.
+    pub fn modulus(mut self, modulus: u64) -> Self {
+        self.modulus = Some(modulus);
+        self
+    }
+
+    /// Sets the `OriginStrategy` used to generate signatures.
+    pub fn strategy(mut self, strategy: S) -> Self {
+        self.strategy = Some(strategy);
+        self
+    }
+
+    /// Sets the `GeneticCode` used to translate codons.
+    pub fn genetic_code(mut self, code: GeneticCode) -> Self {
+        self.code = code;
+        self
+    }
+
+    /// Sets the reading frame (0, 1, or 2) used by `BioSigAnalyzer::analyze` and
+    /// `BioSigAnalyzer::analyze_indel`.
+    pub fn frame(mut self, frame: usize) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Sets the point-mutation rule applied to the base at the mutation site.
+    pub fn base_cycle(mut self, base_cycle: BaseCycleFn) -> Self {
+        self.base_cycle = base_cycle;
+        self
+    }
+
+    /// Builds the configured `BioSigAnalyzer`.
+    ///
+    /// # Panics
+    /// Panics if the modulus or strategy has not been set, or if the frame is not 0, 1, or 2.
+    pub fn build(self) -> BioSigAnalyzer<S> {
+        let modulus = self.modulus.expect("BioSigAnalyzerBuilder requires a modulus.");
+        let strategy = self.strategy.expect("BioSigAnalyzerBuilder requires a strategy.");
+        assert!(self.frame <= 2, "Reading frame must be 0, 1, or 2.");
+
+        BioSigAnalyzer {
+            frame: self.frame,
+            base_cycle: self.base_cycle,
+            ..BioSigAnalyzer::with_code(modulus, strategy, self.code)
+        }
+    }
+}
+
+/// The default point-mutation rule: cycle through A -> C -> G -> T -> A.
+///
+/// Every substitution under this rule changes the base, and half of them are
+/// transversions (purine <-> pyrimidine).
+pub fn cycle_base(base: char) -> Option<char> {
+    match base {
+        'A' => Some('C'),
+        'C' => Some('G'),
+        'G' => Some('T'),
+        'T' => Some('A'),
+        _ => None,
+    }
+}
+
+/// A transition-only point-mutation rule: A <-> G and C <-> T.
+///
+/// Transitions swap a purine for a purine or a pyrimidine for a pyrimidine, and are
+/// the most common class of point mutation in real genomes. Pass this to
+/// `BioSigAnalyzerBuilder::base_cycle` to model them.
+pub fn transition_base(base: char) -> Option<char> {
+    match base {
+        'A' => Some('G'),
+        'G' => Some('A'),
+        'C' => Some('T'),
+        'T' => Some('C'),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::mutation::MutationType;
+    use crate::strategy::Fixed;
+
+    #[test]
+    fn test_try_analyze_errors() {
+        // Under Fixed(0) the signatures of 2, 5 and 7 are 0, 8 and 12.
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+        let error = |p, dna| analyzer.try_analyze(p, dna).err();
+        assert_eq!(error(5, ""), Some(BioSigError::SequenceTooShort));
+        // Position 12 of 13 starts a codon that runs off the end.
+        assert_eq!(error(7, "ATGGCCTTTAAAG"), Some(BioSigError::SequenceTooShort));
+        assert_eq!(
+            analyzer.try_analyze_frame(2, "ATGGCC", 1).err(),
+            Some(BioSigError::SiteBeforeFrame)
+        );
+        assert_eq!(error(5, "ATGGCCTNTAAA"), Some(BioSigError::InvalidBase('N')));
+        assert_eq!(error(5, "atggcctttaaa"), Some(BioSigError::InvalidBase('t')));
+        // 'U' is a valid base, but the default rule cannot mutate it.
+        assert_eq!(error(5, "AUGGCCUUUAAA"), Some(BioSigError::InvalidBase('U')));
+        assert_eq!(
+            analyzer.translate("XYZ"),
+            Err(BioSigError::UntranslatableCodon("XYZ".to_string()))
+        );
+        assert_eq!(BioSigError::InvalidBase('N').to_string(), "invalid or unmutable base 'N'");
+
+        // `analyze` maps every error to `None` and agrees on success.
+        assert!(analyzer.analyze(5, "ATGGCCTNTAAA").is_none());
+        let (signature, mutation) = analyzer.try_analyze(5, "ATGGCCTTTAAAGG").unwrap();
+        let (expected_signature, expected) = analyzer.analyze(5, "ATGGCCTTTAAAGG").unwrap();
+        assert_eq!(signature, expected_signature);
+        assert_eq!(mutation.mutated_codon, expected.mutated_codon);
+    }
+
+    #[test]
+    fn test_analyze_ignores_invalid_bases_outside_codon() {
+        // The signature of 5 under Fixed(0) is 8, so only codon "TTT" at 6..9 is read.
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+        let (_, clean) = analyzer.analyze(5, "ATGGCCTTTAAA").unwrap();
+        let (_, masked) = analyzer.analyze(5, "NTGgcCTTTA A").unwrap();
+        assert_eq!(masked.original_codon, "TTT");
+        assert_eq!(masked.mutated_codon, clean.mutated_codon);
+        // Multi-byte characters before the codon do not shift it.
+        let (_, accented) = analyzer.analyze(5, "ÅTGGCTTTAAA").unwrap();
+        assert_eq!(accented.original_codon, "TTT");
+    }
+
+    #[test]
+    fn test_analyze_frame_shifts_codon_grid() {
+        // The signature of 5 under Fixed(0) is 8, so position 8 mutates (T -> A).
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+        let dna = "ATGGCCTTTAAAGG";
+
+        let (_, frame0) = analyzer.analyze_frame(5, dna, 0).unwrap();
+        let (_, frame1) = analyzer.analyze_frame(5, dna, 1).unwrap();
+        assert_eq!(frame0.original_codon, "TTT");
+        assert_eq!(frame1.original_codon, "TTA");
+        assert_ne!(frame0.original_codon, frame1.original_codon);
+        assert_eq!(analyzer.analyze(5, dna).unwrap().1.original_codon, "TTT");
+
+        // The signature of 2 is 0: position 0 precedes frame 1's first codon.
+        assert!(analyzer.analyze_frame(2, dna, 1).is_none());
+    }
+
+    #[test]
+    fn test_analyze_reverse_reads_complementary_strand() {
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+        let dna = "ATGGCCTTTAAA";
+        // The reverse strand is "TTTAAAGGCCAT"; position 8 falls in codon "GGC".
+        let (_, mutation) = analyzer.analyze_reverse(5, dna, 0).unwrap();
+        assert_eq!(mutation.original_codon, "GGC");
+        assert!(analyzer.analyze_reverse(5, "ATGNNN", 0).is_none());
+    }
+
+    #[test]
+    fn test_analyze_indel() {
+        // With Fixed(0), the signature of 5 is 5 + 3 = 8, so the edit hits position 8,
+        // inside the codon TTT (Phenylalanine) at positions 6..9.
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+
+        let (signature, deletion) = analyzer.analyze_indel(5, "ATGGCCTTTAAA", false).unwrap();
+        assert_eq!(signature, 8);
+        assert_eq!(deletion.mutation_type, MutationType::Frameshift);
+        assert_eq!(deletion.original_amino_acid, AminoAcid::Phenylalanine);
+        assert_eq!(deletion.mutated_codon, "TTA");
+
+        let (_, insertion) = analyzer.analyze_indel(5, "ATGGCCTTTAAA", true).unwrap();
+        assert_eq!(insertion.mutation_type, MutationType::Frameshift);
+        assert_eq!(insertion.mutated_codon, "TTA");
+
+        // An insertion restoring a length divisible by 3 keeps the frame intact.
+        let (_, in_frame) = analyzer.analyze_indel(5, "ATGGCCTTTAAAGG", true).unwrap();
+        assert_eq!(in_frame.mutation_type, MutationType::Missense);
+        assert_eq!(in_frame.mutated_amino_acid, AminoAcid::Leucine);
+
+        assert!(analyzer.analyze_indel(5, "", true).is_none());
+    }
+
+    #[test]
+    fn test_analyze_indel_rejects_multibyte_characters() {
+        let analyzer = BioSigAnalyzer::new(1000, Fixed(0));
+        // The signature of 3 is 5, a byte offset inside the two-byte 'é'.
+        assert!(analyzer.analyze_indel(3, "AAéGGGCCC", true).is_none());
+        assert!(analyzer.analyze_indel(3, "AAéGGGCCC", false).is_none());
+        // Deleting from TTT shifts half of the 'é' into the re-read codon.
+        assert!(analyzer.analyze_indel(5, "ATGGCCTTTé", false).is_none());
+        // A multibyte character away from the edit site is harmless.
+        let (_, deletion) = analyzer.analyze_indel(5, "ATGGCCTTTAéA", false).unwrap();
+        assert_eq!(deletion.mutated_codon, "TTA");
+    }
+
+    #[test]
+    fn test_builder_applies_configuration() {
+        let dna = "ATGGCCTTTAAAGG";
+
+        let custom = BioSigAnalyzer::builder()
+            .modulus(1000)
+            .strategy(Fixed(0))
+            .base_cycle(transition_base)
+            .build();
+        // Position 8 holds T, which the transition rule turns into C: TTT -> TTC.
+        let (_, mutation) = custom.analyze(5, dna).unwrap();
+        assert_eq!(mutation.mutated_codon, "TTC");
+        assert_eq!(mutation.mutation_type, MutationType::Silent);
+        let (_, default) = BioSigAnalyzer::new(1000, Fixed(0)).analyze(5, dna).unwrap();
+        assert_eq!(default.mutated_codon, "TTA");
+
+        let framed = BioSigAnalyzer::builder().modulus(1000).strategy(Fixed(0)).frame(1).build();
+        assert_eq!(framed.analyze(5, dna).unwrap().1.original_codon, "TTA");
+        // Indels read the same frame-1 codon, TTA at positions 7..10.
+        let (_, deletion) = framed.analyze_indel(5, dna, false).unwrap();
+        assert_eq!(deletion.original_codon, "TTA");
+        assert_eq!(deletion.mutated_codon, "TAA");
+        assert_eq!(deletion.mutation_type, MutationType::Frameshift);
+        let (_, insertion) = framed.analyze_indel(5, dna, true).unwrap();
+        assert_eq!(insertion.mutated_codon, "TAT");
+        // In an 8-base sequence the site is position 0, before frame 1's first codon.
+        assert!(framed.analyze_indel(5, "ATGGCCTT", true).is_none());
+        assert!(BioSigAnalyzer::new(1000, Fixed(0)).analyze_indel(5, "ATGGCCTT", true).is_some());
+
+        let mito = BioSigAnalyzer::builder()
+            .modulus(1000)
+            .strategy(Fixed(0))
+            .genetic_code(GeneticCode::VertebrateMito)
+            .build();
+        // Position 8 of "AAAAAATGA" is A -> C: the mito code reads UGA as Tryptophan.
+        let (_, mutation) = mito.analyze(5, "AAAAAATGA").unwrap();
+        assert_eq!(mutation.original_amino_acid, AminoAcid::Tryptophan);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_builder_requires_strategy() {
+        BioSigAnalyzerBuilder::<Fixed>::new().modulus(10).build();
+    }
+
+    #[test]
+    fn test_substitution_rule_shapes_mutation_types() {
+        let dna = "ATGGCCTTTAAAGGGCCCTTTAAATGCTGGCATCAGTTA";
+        let primes = crate::primes::primes_in_range(3, 400);
+        let analyze_all = |rule: BaseCycleFn| -> Vec<MutationType> {
+            let analyzer = BioSigAnalyzer::builder().modulus(97).strategy(Fixed(0)).base_cycle(rule).build();
+            primes
+                .iter()
+                .filter_map(|&p| analyzer.analyze(p, dna))
+                .map(|(_, mutation)| mutation.mutation_type)
+                .collect()
+        };
+
+        let identity = analyze_all(Some);
+        assert!(!identity.is_empty());
+        assert!(identity.iter().all(|&kind| kind == MutationType::Silent));
+
+        let cycled = analyze_all(cycle_base);
+        let transitions = analyze_all(transition_base);
+        assert_eq!(cycled.len(), transitions.len());
+        assert_ne!(cycled, transitions);
+    }
+}
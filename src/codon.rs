@@ -1,9 +1,12 @@
 //! Provides structures and logic for working with genetic codons and the standard DNA codon table.
 
 use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
 
 /// Represents a single amino acid or a Stop signal.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum AminoAcid {
     Alanine, Arginine, Asparagine, AsparticAcid, Cysteine,
     GlutamicAcid, Glutamine, Glycine, Histidine, Isoleucine,
@@ -13,6 +16,170 @@ pub enum AminoAcid {
     Stop, // Represents a translation stop signal
 }
 
+/// The error returned when parsing an unrecognized amino acid code.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseAminoAcidError {
+    input: String,
+}
+
+impl fmt::Display for ParseAminoAcidError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "unknown amino acid code {:?}; expected a one-letter (e.g. 'M', '*') or three-letter (e.g. \"Met\") code",
+            self.input
+        )
+    }
+}
+
+impl std::error::Error for ParseAminoAcidError {}
+
+/// A coarse biochemical classification of amino acid side chains.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AminoClass {
+    /// Nonpolar, aliphatic side chains (G, A, V, L, I, M, P).
+    Hydrophobic,
+    /// Polar, uncharged side chains (S, T, C, N, Q).
+    Polar,
+    /// Negatively charged side chains (D, E).
+    Acidic,
+    /// Positively charged side chains (K, R, H).
+    Basic,
+    /// Aromatic side chains (F, Y, W).
+    Aromatic,
+    /// The translation stop signal, which has no side chain.
+    Stop,
+}
+
+impl AminoAcid {
+    /// Returns the biochemical class of this amino acid's side chain.
+    pub fn property(&self) -> AminoClass {
+        match self {
+            AminoAcid::Glycine
+            | AminoAcid::Alanine
+            | AminoAcid::Valine
+            | AminoAcid::Leucine
+            | AminoAcid::Isoleucine
+            | AminoAcid::Methionine
+            | AminoAcid::Proline => AminoClass::Hydrophobic,
+            AminoAcid::Serine
+            | AminoAcid::Threonine
+            | AminoAcid::Cysteine
+            | AminoAcid::Asparagine
+            | AminoAcid::Glutamine => AminoClass::Polar,
+            AminoAcid::AsparticAcid | AminoAcid::GlutamicAcid => AminoClass::Acidic,
+            AminoAcid::Lysine | AminoAcid::Arginine | AminoAcid::Histidine => AminoClass::Basic,
+            AminoAcid::Phenylalanine | AminoAcid::Tyrosine | AminoAcid::Tryptophan => {
+                AminoClass::Aromatic
+            }
+            AminoAcid::Stop => AminoClass::Stop,
+        }
+    }
+
+    /// All amino acids followed by the Stop signal, in declaration order.
+    pub const ALL: [AminoAcid; 21] = [
+        AminoAcid::Alanine, AminoAcid::Arginine, AminoAcid::Asparagine, AminoAcid::AsparticAcid,
+        AminoAcid::Cysteine, AminoAcid::GlutamicAcid, AminoAcid::Glutamine, AminoAcid::Glycine,
+        AminoAcid::Histidine, AminoAcid::Isoleucine, AminoAcid::Leucine, AminoAcid::Lysine,
+        AminoAcid::Methionine, AminoAcid::Phenylalanine, AminoAcid::Proline, AminoAcid::Serine,
+        AminoAcid::Threonine, AminoAcid::Tryptophan, AminoAcid::Tyrosine, AminoAcid::Valine,
+        AminoAcid::Stop,
+    ];
+
+    /// Returns the IUPAC one-letter code, e.g. `'M'` for Methionine, or `'*'` for Stop.
+    pub fn one_letter(&self) -> char {
+        match self {
+            AminoAcid::Alanine => 'A',
+            AminoAcid::Arginine => 'R',
+            AminoAcid::Asparagine => 'N',
+            AminoAcid::AsparticAcid => 'D',
+            AminoAcid::Cysteine => 'C',
+            AminoAcid::GlutamicAcid => 'E',
+            AminoAcid::Glutamine => 'Q',
+            AminoAcid::Glycine => 'G',
+            AminoAcid::Histidine => 'H',
+            AminoAcid::Isoleucine => 'I',
+            AminoAcid::Leucine => 'L',
+            AminoAcid::Lysine => 'K',
+            AminoAcid::Methionine => 'M',
+            AminoAcid::Phenylalanine => 'F',
+            AminoAcid::Proline => 'P',
+            AminoAcid::Serine => 'S',
+            AminoAcid::Threonine => 'T',
+            AminoAcid::Tryptophan => 'W',
+            AminoAcid::Tyrosine => 'Y',
+            AminoAcid::Valine => 'V',
+            AminoAcid::Stop => '*',
+        }
+    }
+
+    /// Returns the IUPAC three-letter code, e.g. `"Met"` for Methionine, or `"Stop"`.
+    pub fn three_letter(&self) -> &'static str {
+        match self {
+            AminoAcid::Alanine => "Ala",
+            AminoAcid::Arginine => "Arg",
+            AminoAcid::Asparagine => "Asn",
+            AminoAcid::AsparticAcid => "Asp",
+            AminoAcid::Cysteine => "Cys",
+            AminoAcid::GlutamicAcid => "Glu",
+            AminoAcid::Glutamine => "Gln",
+            AminoAcid::Glycine => "Gly",
+            AminoAcid::Histidine => "His",
+            AminoAcid::Isoleucine => "Ile",
+            AminoAcid::Leucine => "Leu",
+            AminoAcid::Lysine => "Lys",
+            AminoAcid::Methionine => "Met",
+            AminoAcid::Phenylalanine => "Phe",
+            AminoAcid::Proline => "Pro",
+            AminoAcid::Serine => "Ser",
+            AminoAcid::Threonine => "Thr",
+            AminoAcid::Tryptophan => "Trp",
+            AminoAcid::Tyrosine => "Tyr",
+            AminoAcid::Valine => "Val",
+            AminoAcid::Stop => "Stop",
+        }
+    }
+}
+
+/// Parses a one-letter (`"M"`, `"*"`) or three-letter (`"Met"`, `"Stop"`) code,
+/// ignoring case and surrounding whitespace.
+impl FromStr for AminoAcid {
+    type Err = ParseAminoAcidError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s.trim();
+        AminoAcid::ALL
+            .into_iter()
+            .find(|aa| {
+                code.eq_ignore_ascii_case(aa.three_letter())
+                    || (code.len() == 1 && code.eq_ignore_ascii_case(&aa.one_letter().to_string()))
+            })
+            .ok_or_else(|| ParseAminoAcidError { input: s.to_string() })
+    }
+}
+
+/// Formats the amino acid as its one-letter code, e.g. `M` or `*`.
+impl fmt::Display for AminoAcid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.one_letter())
+    }
+}
+
+/// Selects which genetic code a `CodonTable` uses.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GeneticCode {
+    /// The standard code used by most nuclear genomes (NCBI table 1).
+    #[default]
+    Standard,
+    /// The vertebrate mitochondrial code (NCBI table 2): `UGA` is Tryptophan,
+    /// `AUA` is Methionine, and `AGA`/`AGG` are Stop.
+    VertebrateMito,
+    /// The bacterial, archaeal and plant plastid code (NCBI table 11). Its codon
+    /// assignments match the standard code; it differs only in alternative start codons.
+    BacterialArchaeal,
+}
+
 /// A struct that holds the standard DNA codon translation table.
 /// It maps three-letter DNA codons (e.g., "AUG") to their corresponding amino acids.
 #[derive(Debug)]
@@ -21,7 +188,7 @@ pub struct CodonTable {
 }
 
 impl Default for CodonTable {
-    /// Creates a new `CodonTable` populated with the standard genetic code.
+    /// Creates a new `CodonTable` populated with all 64 codons of the standard genetic code.
     fn default() -> Self {
         let mut map = HashMap::new();
         // Alanine
@@ -36,10 +203,33 @@ impl Default for CodonTable {
         map.insert("CGG".to_string(), AminoAcid::Arginine);
         map.insert("AGA".to_string(), AminoAcid::Arginine);
         map.insert("AGG".to_string(), AminoAcid::Arginine);
-        // ... and so on for all other amino acids ...
-        // Phenylalanine
-        map.insert("UUU".to_string(), AminoAcid::Phenylalanine);
-        map.insert("UUC".to_string(), AminoAcid::Phenylalanine);
+        // Asparagine
+        map.insert("AAU".to_string(), AminoAcid::Asparagine);
+        map.insert("AAC".to_string(), AminoAcid::Asparagine);
+        // Aspartic acid
+        map.insert("GAU".to_string(), AminoAcid::AsparticAcid);
+        map.insert("GAC".to_string(), AminoAcid::AsparticAcid);
+        // Cysteine
+        map.insert("UGU".to_string(), AminoAcid::Cysteine);
+        map.insert("UGC".to_string(), AminoAcid::Cysteine);
+        // Glutamine
+        map.insert("CAA".to_string(), AminoAcid::Glutamine);
+        map.insert("CAG".to_string(), AminoAcid::Glutamine);
+        // Glutamic acid
+        map.insert("GAA".to_string(), AminoAcid::GlutamicAcid);
+        map.insert("GAG".to_string(), AminoAcid::GlutamicAcid);
+        // Glycine
+        map.insert("GGU".to_string(), AminoAcid::Glycine);
+        map.insert("GGC".to_string(), AminoAcid::Glycine);
+        map.insert("GGA".to_string(), AminoAcid::Glycine);
+        map.insert("GGG".to_string(), AminoAcid::Glycine);
+        // Histidine
+        map.insert("CAU".to_string(), AminoAcid::Histidine);
+        map.insert("CAC".to_string(), AminoAcid::Histidine);
+        // Isoleucine
+        map.insert("AUU".to_string(), AminoAcid::Isoleucine);
+        map.insert("AUC".to_string(), AminoAcid::Isoleucine);
+        map.insert("AUA".to_string(), AminoAcid::Isoleucine);
         // Leucine
         map.insert("UUA".to_string(), AminoAcid::Leucine);
         map.insert("UUG".to_string(), AminoAcid::Leucine);
@@ -47,12 +237,45 @@ impl Default for CodonTable {
         map.insert("CUC".to_string(), AminoAcid::Leucine);
         map.insert("CUA".to_string(), AminoAcid::Leucine);
         map.insert("CUG".to_string(), AminoAcid::Leucine);
+        // Lysine
+        map.insert("AAA".to_string(), AminoAcid::Lysine);
+        map.insert("AAG".to_string(), AminoAcid::Lysine);
+        // Methionine (Start codon)
+        map.insert("AUG".to_string(), AminoAcid::Methionine);
+        // Phenylalanine
+        map.insert("UUU".to_string(), AminoAcid::Phenylalanine);
+        map.insert("UUC".to_string(), AminoAcid::Phenylalanine);
+        // Proline
+        map.insert("CCU".to_string(), AminoAcid::Proline);
+        map.insert("CCC".to_string(), AminoAcid::Proline);
+        map.insert("CCA".to_string(), AminoAcid::Proline);
+        map.insert("CCG".to_string(), AminoAcid::Proline);
+        // Serine
+        map.insert("UCU".to_string(), AminoAcid::Serine);
+        map.insert("UCC".to_string(), AminoAcid::Serine);
+        map.insert("UCA".to_string(), AminoAcid::Serine);
+        map.insert("UCG".to_string(), AminoAcid::Serine);
+        map.insert("AGU".to_string(), AminoAcid::Serine);
+        map.insert("AGC".to_string(), AminoAcid::Serine);
+        // Threonine
+        map.insert("ACU".to_string(), AminoAcid::Threonine);
+        map.insert("ACC".to_string(), AminoAcid::Threonine);
+        map.insert("ACA".to_string(), AminoAcid::Threonine);
+        map.insert("ACG".to_string(), AminoAcid::Threonine);
+        // Tryptophan
+        map.insert("UGG".to_string(), AminoAcid::Tryptophan);
+        // Tyrosine
+        map.insert("UAU".to_string(), AminoAcid::Tyrosine);
+        map.insert("UAC".to_string(), AminoAcid::Tyrosine);
+        // Valine
+        map.insert("GUU".to_string(), AminoAcid::Valine);
+        map.insert("GUC".to_string(), AminoAcid::Valine);
+        map.insert("GUA".to_string(), AminoAcid::Valine);
+        map.insert("GUG".to_string(), AminoAcid::Valine);
         // Stop codons
         map.insert("UAA".to_string(), AminoAcid::Stop);
         map.insert("UAG".to_string(), AminoAcid::Stop);
         map.insert("UGA".to_string(), AminoAcid::Stop);
-        // Methionine (Start codon)
-        map.insert("AUG".to_string(), AminoAcid::Methionine);
 
         Self { map }
     }
@@ -64,6 +287,21 @@ impl CodonTable {
         Self::default()
     }
 
+    /// Creates a `CodonTable` for the given genetic code.
+    pub fn with_code(code: GeneticCode) -> Self {
+        let mut table = Self::default();
+        match code {
+            GeneticCode::Standard | GeneticCode::BacterialArchaeal => {}
+            GeneticCode::VertebrateMito => {
+                table.map.insert("UGA".to_string(), AminoAcid::Tryptophan);
+                table.map.insert("AUA".to_string(), AminoAcid::Methionine);
+                table.map.insert("AGA".to_string(), AminoAcid::Stop);
+                table.map.insert("AGG".to_string(), AminoAcid::Stop);
+            }
+        }
+        table
+    }
+
     /// Translates a three-letter DNA codon string into an `Option<AminoAcid>`.
     ///
     /// # Arguments
@@ -76,4 +314,267 @@ impl CodonTable {
         let rna_codon = codon.replace('T', "U");
         self.map.get(&rna_codon).cloned()
     }
-}
\ No newline at end of file
+
+    /// Translates a whole sequence codon by codon, reading frame 0.
+    ///
+    /// The sequence is split into non-overlapping codons from its first base; leftover
+    /// bases at the end (when the length is not a multiple of 3) are ignored. Each entry
+    /// is the result of `translate` for that codon, so untranslatable codons are `None`.
+    pub fn translate_sequence(&self, dna: &str) -> Vec<Option<AminoAcid>> {
+        dna.as_bytes()
+            .chunks_exact(3)
+            .map(|codon| std::str::from_utf8(codon).ok().and_then(|codon| self.translate(codon)))
+            .collect()
+    }
+
+    /// Translates a sequence into a protein string of one-letter codes, e.g. `"MF"`.
+    ///
+    /// Translation reads frame 0 as in `translate_sequence` and stops at the first Stop
+    /// codon, which is not included. Untranslatable codons are written as `X`.
+    pub fn translate_protein(&self, dna: &str) -> String {
+        self.translate_sequence(dna)
+            .into_iter()
+            .take_while(|&aa| aa != Some(AminoAcid::Stop))
+            .map(|aa| aa.map_or('X', |aa| aa.one_letter()))
+            .collect()
+    }
+
+    /// Lists all RNA codons that encode the given amino acid (or Stop signal).
+    ///
+    /// # Returns
+    /// The codons in sorted order, e.g. `["UAA", "UAG", "UGA"]` for `AminoAcid::Stop`.
+    /// Returns an empty vector if no codon in the table maps to `aa`.
+    pub fn codons_for(&self, aa: AminoAcid) -> Vec<String> {
+        let mut codons: Vec<String> = self
+            .map
+            .iter()
+            .filter(|&(_, &mapped)| mapped == aa)
+            .map(|(codon, _)| codon.clone())
+            .collect();
+        codons.sort();
+        codons
+    }
+
+    /// Reassigns a codon to a different amino acid, e.g. `CUG` to Serine as in some yeasts.
+    ///
+    /// The codon may be given in DNA (`T`) or RNA (`U`) form and in either case;
+    /// it is stored in uppercase RNA form so that `translate` picks it up.
+    ///
+    /// # Panics
+    /// Panics if `codon` is not exactly three characters from `A`, `C`, `G`, `T`, `U`.
+    pub fn override_codon(&mut self, codon: &str, aa: AminoAcid) {
+        let rna_codon = codon.to_ascii_uppercase().replace('T', "U");
+        assert!(
+            rna_codon.len() == 3 && rna_codon.chars().all(|c| matches!(c, 'A' | 'C' | 'G' | 'U')),
+            "Codon must be a triple of A, C, G, T or U, got {codon:?}."
+        );
+        self.map.insert(rna_codon, aa);
+    }
+}
+
+/// Counts the nucleotides in a DNA or RNA sequence.
+///
+/// Bases are counted case-insensitively under their uppercase letter; `T` and `U`
+/// are kept as separate keys, so mixed input remains visible. Characters other
+/// than `A`, `C`, `G`, `T`, `U` (e.g. `N` or whitespace) are ignored.
+pub fn base_counts(dna: &str) -> HashMap<char, usize> {
+    let mut counts = HashMap::new();
+    for base in dna.chars().map(|c| c.to_ascii_uppercase()) {
+        if matches!(base, 'A' | 'C' | 'G' | 'T' | 'U') {
+            *counts.entry(base).or_insert(0) += 1;
+        }
+    }
+    counts
+}
+
+/// Returns the fraction of `G` and `C` bases in a DNA or RNA sequence.
+///
+/// Only valid bases (see `base_counts`) count towards the total, so ambiguous
+/// characters such as `N` neither raise nor lower the result. Returns 0.0 if the
+/// sequence contains no valid bases.
+pub fn gc_content(dna: &str) -> f64 {
+    let counts = base_counts(dna);
+    let total: usize = counts.values().sum();
+    if total == 0 {
+        return 0.0;
+    }
+    let gc = counts.get(&'G').unwrap_or(&0) + counts.get(&'C').unwrap_or(&0);
+    gc as f64 / total as f64
+}
+
+/// Returns the reverse complement of a DNA or RNA sequence.
+///
+/// Bases are complemented (`A`↔`T`, `C`↔`G`) and the result is reversed. Case is
+/// preserved per base. If the sequence contains `U`, it is treated as RNA and `A`
+/// complements to `U` instead of `T`.
+///
+/// # Returns
+/// `None` if the sequence contains a character other than `A`, `C`, `G`, `T`, `U`
+/// (in either case), or mixes `T` and `U`.
+pub fn reverse_complement(dna: &str) -> Option<String> {
+    let has_t = dna.contains(['T', 't']);
+    let is_rna = dna.contains(['U', 'u']);
+    if has_t && is_rna {
+        return None;
+    }
+    dna.chars()
+        .rev()
+        .map(|base| {
+            let complement = match base.to_ascii_uppercase() {
+                'A' if is_rna => 'U',
+                'A' => 'T',
+                'T' | 'U' => 'A',
+                'C' => 'G',
+                'G' => 'C',
+                _ => return None,
+            };
+            Some(if base.is_ascii_lowercase() { complement.to_ascii_lowercase() } else { complement })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_standard_table_is_complete() {
+        let table = CodonTable::new();
+        assert_eq!(table.map.len(), 64);
+        let bases = ['A', 'C', 'G', 'U'];
+        for a in bases {
+            for b in bases {
+                for c in bases {
+                    let codon: String = [a, b, c].iter().collect();
+                    assert!(table.translate(&codon).is_some(), "{codon} is untranslatable");
+                }
+            }
+        }
+        assert_eq!(table.translate("GGG"), Some(AminoAcid::Glycine));
+        assert_eq!(table.translate("TGG"), Some(AminoAcid::Tryptophan));
+    }
+
+    #[test]
+    fn test_genetic_codes_differ_on_reassigned_codons() {
+        let standard = CodonTable::with_code(GeneticCode::Standard);
+        let mito = CodonTable::with_code(GeneticCode::VertebrateMito);
+        let bacterial = CodonTable::with_code(GeneticCode::BacterialArchaeal);
+
+        assert_eq!(standard.translate("UGA"), Some(AminoAcid::Stop));
+        assert_eq!(mito.translate("UGA"), Some(AminoAcid::Tryptophan));
+        assert_eq!(standard.translate("AUA"), Some(AminoAcid::Isoleucine));
+        assert_eq!(mito.translate("AUA"), Some(AminoAcid::Methionine));
+        assert_eq!(standard.translate("AGA"), Some(AminoAcid::Arginine));
+        assert_eq!(mito.translate("AGG"), Some(AminoAcid::Stop));
+
+        assert_eq!(mito.map.len(), 64);
+        assert_eq!(bacterial.map, standard.map);
+    }
+
+    #[test]
+    fn test_override_codon() {
+        let mut table = CodonTable::new();
+        assert_eq!(table.translate("CUG"), Some(AminoAcid::Leucine));
+        table.override_codon("CTG", AminoAcid::Serine);
+        assert_eq!(table.translate("CUG"), Some(AminoAcid::Serine));
+        assert_eq!(table.translate("CTG"), Some(AminoAcid::Serine));
+    }
+
+    #[test]
+    fn test_codons_for() {
+        let table = CodonTable::new();
+        assert_eq!(
+            table.codons_for(AminoAcid::Leucine),
+            vec!["CUA", "CUC", "CUG", "CUU", "UUA", "UUG"]
+        );
+        assert_eq!(table.codons_for(AminoAcid::Methionine), vec!["AUG"]);
+        for codon in table.codons_for(AminoAcid::Stop) {
+            assert_eq!(table.translate(&codon), Some(AminoAcid::Stop));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_override_codon_rejects_malformed_triple() {
+        CodonTable::new().override_codon("AUGX", AminoAcid::Methionine);
+    }
+
+    #[test]
+    fn test_reverse_complement() {
+        assert_eq!(reverse_complement("AGCT").as_deref(), Some("AGCT"));
+        assert_eq!(reverse_complement("ATGC").as_deref(), Some("GCAT"));
+        assert_eq!(reverse_complement("aTgc").as_deref(), Some("gcAt"));
+        assert_eq!(reverse_complement("AUGC").as_deref(), Some("GCAU"));
+        assert_eq!(reverse_complement("ATGN"), None);
+        assert_eq!(reverse_complement("ATU"), None);
+        assert_eq!(reverse_complement("").as_deref(), Some(""));
+
+        let dna = "ATGGCCttTAAAGG";
+        let twice = reverse_complement(&reverse_complement(dna).unwrap()).unwrap();
+        assert_eq!(twice, dna);
+    }
+
+    #[test]
+    fn test_sequence_composition() {
+        assert_eq!(gc_content("GGCC"), 1.0);
+        assert_eq!(gc_content("ATAT"), 0.0);
+        assert_eq!(gc_content("AUGC"), 0.5);
+        assert_eq!(gc_content("gcNNat"), 0.5);
+        assert_eq!(gc_content(""), 0.0);
+        assert_eq!(gc_content("NNN"), 0.0);
+
+        let counts = base_counts("AaUtG-x");
+        assert_eq!(counts.get(&'A'), Some(&2));
+        assert_eq!(counts.get(&'U'), Some(&1));
+        assert_eq!(counts.get(&'T'), Some(&1));
+        assert_eq!(counts.get(&'G'), Some(&1));
+        assert_eq!(counts.get(&'C'), None);
+        assert_eq!(counts.values().sum::<usize>(), 5);
+    }
+
+    #[test]
+    fn test_amino_acid_symbols() {
+        assert_eq!(AminoAcid::Methionine.one_letter(), 'M');
+        assert_eq!(AminoAcid::Methionine.three_letter(), "Met");
+        assert_eq!(AminoAcid::Stop.one_letter(), '*');
+        assert_eq!(AminoAcid::Tryptophan.to_string(), "W");
+        assert_eq!(AminoAcid::Stop.to_string(), "*");
+
+        let one: std::collections::HashSet<char> = AminoAcid::ALL.iter().map(|aa| aa.one_letter()).collect();
+        let three: std::collections::HashSet<&str> = AminoAcid::ALL.iter().map(|aa| aa.three_letter()).collect();
+        assert_eq!(one.len(), 21);
+        assert_eq!(three.len(), 21);
+    }
+
+    #[test]
+    fn test_parse_amino_acid() {
+        for aa in AminoAcid::ALL {
+            assert_eq!(aa.to_string().parse::<AminoAcid>(), Ok(aa));
+            assert_eq!(aa.three_letter().parse::<AminoAcid>(), Ok(aa));
+            assert_eq!(aa.three_letter().to_uppercase().parse::<AminoAcid>(), Ok(aa));
+        }
+        assert_eq!("m".parse::<AminoAcid>(), Ok(AminoAcid::Methionine));
+        assert_eq!(" stop ".parse::<AminoAcid>(), Ok(AminoAcid::Stop));
+        assert_eq!("*".parse::<AminoAcid>(), Ok(AminoAcid::Stop));
+
+        let err = "Xyz".parse::<AminoAcid>().unwrap_err();
+        assert!(err.to_string().contains("\"Xyz\""));
+        assert!("B".parse::<AminoAcid>().is_err());
+        assert!("".parse::<AminoAcid>().is_err());
+    }
+
+    #[test]
+    fn test_translate_sequence() {
+        let table = CodonTable::new();
+        assert_eq!(table.translate_protein("AUGUUUUAA"), "MF");
+        assert_eq!(table.translate_protein("ATGTTTTAAGGG"), "MF");
+        assert_eq!(table.translate_protein("AUGNNNGG"), "MX");
+        assert_eq!(table.translate_protein(""), "");
+
+        assert_eq!(
+            table.translate_sequence("AUGUAAGGGCC"),
+            vec![Some(AminoAcid::Methionine), Some(AminoAcid::Stop), Some(AminoAcid::Glycine)]
+        );
+        assert_eq!(table.translate_sequence("AUGXYZ"), vec![Some(AminoAcid::Methionine), None]);
+    }
+}
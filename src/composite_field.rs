@@ -1,6 +1,7 @@
 //! Provides a simple utility for identifying composite numbers within a given range.
 
 use crate::primes;
+use std::cmp::Reverse;
 
 /// A utility struct that finds all composite numbers within a specified range.
 #[derive(Debug)]
@@ -29,4 +30,84 @@ impl CompositeField {
             .filter(|&n| n > 1 && !primes::is_prime(n))
             .collect()
     }
-}
\ No newline at end of file
+
+    /// Generates a vector of the semiprimes in the range, i.e. the composites with
+    /// exactly two prime factors such as RSA-style moduli `p * q`.
+    pub fn semiprimes(&self) -> Vec<u64> {
+        (self.range_start..=self.range_end)
+            .filter(|&n| primes::is_semiprime(n))
+            .collect()
+    }
+
+    /// Pairs each composite in the range with its prime factor mass.
+    ///
+    /// # Returns
+    /// A `Vec` of `(composite, prime_factor_mass(composite))` in increasing order.
+    pub fn composites_with_mass(&self) -> Vec<(u64, u64)> {
+        self.composites()
+            .into_iter()
+            .map(|c| (c, primes::prime_factor_mass(c)))
+            .collect()
+    }
+
+    /// Finds the composite with the largest prime factor mass in the range.
+    ///
+    /// # Returns
+    /// `Some((composite, mass))`, preferring the smallest composite on ties, or `None`
+    /// if the range contains no composites.
+    pub fn heaviest(&self) -> Option<(u64, u64)> {
+        self.composites_with_mass()
+            .into_iter()
+            .max_by_key(|&(c, mass)| (mass, Reverse(c)))
+    }
+
+    /// Lists every prime gap in the range together with the number of composites it contains.
+    ///
+    /// # Returns
+    /// A `Vec` of `(prev_prime, next_prime, composite_count)` for each pair of consecutive
+    /// primes within the range.
+    pub fn symmetric_gaps(&self) -> Vec<(u64, u64, u64)> {
+        primes::primes_in_range(self.range_start, self.range_end)
+            .windows(2)
+            .map(|w| (w[0], w[1], w[1] - w[0] - 1))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_semiprimes() {
+        let field = CompositeField::new(1, 30);
+        assert_eq!(field.semiprimes(), vec![4, 6, 9, 10, 14, 15, 21, 22, 25, 26]);
+    }
+
+    #[test]
+    fn test_composites_with_mass() {
+        let field = CompositeField::new(1, 20);
+        let with_mass = field.composites_with_mass();
+        assert_eq!(with_mass[..4], [(4, 2), (6, 2), (8, 3), (9, 2)]);
+        assert_eq!(with_mass.len(), field.composites().len());
+        assert_eq!(field.heaviest(), Some((16, 4)));
+
+        // 8 and 12 both have mass 3; the smaller wins.
+        assert_eq!(CompositeField::new(1, 15).heaviest(), Some((8, 3)));
+        assert_eq!(CompositeField::new(13, 13).heaviest(), None);
+    }
+
+    #[test]
+    fn test_symmetric_gaps() {
+        let field = CompositeField::new(1, 20);
+        let expected = vec![
+            (2, 3, 0), (3, 5, 1), (5, 7, 1), (7, 11, 3),
+            (11, 13, 1), (13, 17, 3), (17, 19, 1),
+        ];
+        assert_eq!(field.symmetric_gaps(), expected);
+
+        let total: u64 = expected.iter().map(|&(_, _, count)| count).sum();
+        let inside = field.composites().into_iter().filter(|&c| c > 2 && c < 19).count();
+        assert_eq!(total, inside as u64);
+    }
+}
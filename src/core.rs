@@ -1,6 +1,10 @@
 //! Core MOMA structures and traits.
 
+    use crate::entropy::Entropy;
+    use crate::origin_drift;
     use crate::primes;
+    use crate::score;
+    use std::collections::HashSet;
 
     /// Defines a strategy for calculating the moving origin for a given prime context.
     ///
@@ -14,6 +18,14 @@
         fn calculate_origin(&self, p: u64) -> u64;
     }
 
+    /// Boxed strategies are strategies too, which allows heterogeneous collections
+    /// such as `Vec<Box<dyn OriginStrategy>>` to drive a `MomaRing`.
+    impl<S: OriginStrategy + ?Sized> OriginStrategy for Box<S> {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            (**self).calculate_origin(p)
+        }
+    }
+
     /// The central struct for performing Moving Origin Modular Arithmetic.
     ///
     /// A `MomaRing` is configured with a modulus and a chosen `OriginStrategy`.
@@ -52,6 +64,31 @@
             (value.wrapping_add(origin)) % self.modulus
         }
 
+        /// Calculates the residue of `value` relative to the origin, in balanced form.
+        ///
+        /// Where `residue` shifts the value forward by the origin, this subtracts it,
+        /// computing `(value - origin) mod modulus`, and then maps the result into the
+        /// balanced range `(-modulus/2, modulus/2]`. The sign tells on which side of the
+        /// moving origin the value lies, and the magnitude how far away it is.
+        ///
+        /// With a modulus of zero there is no reduction, and the wrapping difference
+        /// `value - origin` is returned as a signed value.
+        ///
+        /// # Parameters
+        /// - `value`: The input value to map to the ring.
+        /// - `prime_context`: The prime number used to determine the origin shift.
+        pub fn residue_centered(&self, value: u64, prime_context: u64) -> i64 {
+            let origin = self.strategy.calculate_origin(prime_context);
+            if self.modulus == 0 {
+                return value.wrapping_sub(origin) as i64;
+            }
+            let m = self.modulus;
+            // Widen so that neither the sum nor the balanced result can overflow.
+            let r = ((value % m) as u128 + (m - origin % m) as u128) % m as u128;
+            let centered = if r > (m / 2) as u128 { r as i128 - m as i128 } else { r as i128 };
+            centered as i64
+        }
+
         /// A convenience method for calculating the "signature" of a prime.
         ///
         /// The signature is defined as the residue of the sum of a prime and its
@@ -61,8 +98,377 @@
         /// - `p`: The prime for which to calculate the signature.
         pub fn signature(&self, p: u64) -> u64 {
             if p < 3 { return 0; } // prev_prime(2) is problematic, handle edge case.
-            let input = p.wrapping_add(primes::prev_prime(p));
-            self.residue(input, p)
+            self.signature_with(p, |p| p.wrapping_add(primes::prev_prime(p)))
+        }
+
+        /// Calculates a signature of `p` using a caller-defined contextual input.
+        ///
+        /// `input_builder` maps the prime to the value passed into `residue`, with `p`
+        /// itself as the prime context. `signature` is this method with the builder
+        /// `|p| p + prev_prime(p)`; other choices such as `|p| p + next_prime(p)` or
+        /// `|p| 2 * p` allow experimenting with alternative signature definitions.
+        ///
+        /// # Parameters
+        /// - `p`: The prime for which to calculate the signature.
+        /// - `input_builder`: A function producing the residue input from `p`.
+        pub fn signature_with(&self, p: u64, input_builder: impl Fn(u64) -> u64) -> u64 {
+            self.residue(input_builder(p), p)
+        }
+
+        /// Calculates the signatures of a run of consecutive primes in one pass.
+        ///
+        /// Instead of calling `prev_prime` for every element, each prime's predecessor
+        /// is taken from the slice itself; only the first element needs a lookup.
+        /// The result is identical to calling `signature` on each element.
+        ///
+        /// # Parameters
+        /// - `primes`: Consecutive primes in ascending order, e.g. `[11, 13, 17, 19]`.
+        ///   Gaps in the sequence produce signatures that differ from `signature`.
+        pub fn signatures(&self, primes: &[u64]) -> Vec<u64> {
+            let Some(&first) = primes.first() else { return Vec::new(); };
+            std::iter::once(self.signature(first))
+                .chain(primes.windows(2).map(|w| {
+                    if w[1] < 3 { 0 } else { self.signature_with(w[1], |p| p.wrapping_add(w[0])) }
+                }))
+                .collect()
+        }
+
+        /// Measures how uniformly the signatures of `primes` cover the ring.
+        ///
+        /// Returns the Shannon entropy of the signatures divided by `log₂(modulus)`, the
+        /// entropy of a perfectly uniform spread over `[0, modulus)`. A score near 1 means
+        /// the strategy spreads signatures evenly; constant signatures score 0. Since at
+        /// most `primes.len()` distinct values can appear, short inputs cannot reach 1
+        /// for large moduli. Returns `0.0` for a modulus below 2 or an empty slice.
+        pub fn uniformity_score(&self, primes: &[u64]) -> f64 {
+            if self.modulus < 2 {
+                return 0.0;
+            }
+            let mut entropy = Entropy::new();
+            entropy.add_all(primes.iter().map(|&p| self.signature(p)));
+            entropy.total_entropy() / (self.modulus as f64).log2()
+        }
+
+        /// Finds all primes in `[start, end]` whose signature equals `target_signature`.
+        ///
+        /// This inverts the forward `signature` map over a bounded range, which is useful
+        /// for studying signature collisions. With a modulus of zero the residue is the
+        /// unreduced input, so only exact matches of that value are returned.
+        ///
+        /// # Parameters
+        /// - `target_signature`: The signature value to search for.
+        /// - `start`: The beginning of the prime range (inclusive).
+        /// - `end`: The end of the prime range (inclusive). Returns an empty vector if `start > end`.
+        pub fn preimages(&self, target_signature: u64, start: u64, end: u64) -> Vec<u64> {
+            primes::primes_in_range(start, end)
+                .into_iter()
+                .filter(|&p| self.signature(p) == target_signature)
+                .collect()
+        }
+
+        /// Traces the orbit of `seed` under the map `p -> next_prime(p + signature(p))`,
+        /// the recurrence used by the MOMA KDF.
+        ///
+        /// The trajectory starts with `seed` and takes at most `steps` steps, so it holds
+        /// at most `steps + 1` values. If a step would revisit an earlier value the orbit
+        /// has entered a cycle, and the trajectory stops just before the repeat. Because
+        /// `next_prime` is strictly increasing, this map can only cycle if the addition
+        /// wraps around `u64::MAX`; in practice orbits run for the full `steps`.
+        ///
+        /// # Parameters
+        /// - `seed`: The starting value, normally a prime.
+        /// - `steps`: The maximum number of steps to take.
+        pub fn orbit(&self, seed: u64, steps: usize) -> Vec<u64> {
+            trace_orbit(seed, steps, |p| self.orbit_step(p))
+        }
+
+        /// Searches for a cycle in the orbit of `seed` (see `orbit`) using Brent's algorithm.
+        ///
+        /// # Parameters
+        /// - `seed`: The starting value, normally a prime.
+        /// - `max_steps`: The maximum number of map evaluations spent looking for a cycle.
+        ///
+        /// # Returns
+        /// `Some((tail_length, period))` if a cycle is found, where `tail_length` is the
+        /// number of steps before the orbit enters the cycle. Returns `None` if no cycle
+        /// is detected within `max_steps`; as noted for `orbit`, that is the expected
+        /// outcome for this strictly increasing map.
+        pub fn find_cycle(&self, seed: u64, max_steps: usize) -> Option<(usize, usize)> {
+            brent_cycle(seed, max_steps, |p| self.orbit_step(p))
+        }
+
+        /// One step of the orbit map: `p -> next_prime(p + signature(p))`.
+        fn orbit_step(&self, p: u64) -> u64 {
+            primes::next_prime(p.wrapping_add(self.signature(p)))
+        }
+    }
+
+    /// Brent's cycle detection over `step` from `seed`, returning `(tail_length, period)`
+    /// or `None` if no cycle shows up within `max_steps` evaluations.
+    fn brent_cycle(seed: u64, max_steps: usize, step: impl Fn(u64) -> u64) -> Option<(usize, usize)> {
+        if max_steps == 0 { return None; }
+
+        // 1. Find the period by moving the tortoise to the hare at each power of two.
+        let mut power = 1;
+        let mut period = 1;
+        let mut tortoise = seed;
+        let mut hare = step(seed);
+        let mut evaluations = 1;
+        while tortoise != hare {
+            if evaluations >= max_steps { return None; }
+            if power == period {
+                tortoise = hare;
+                power *= 2;
+                period = 0;
+            }
+            hare = step(hare);
+            period += 1;
+            evaluations += 1;
+        }
+
+        // 2. Find the tail length with two pointers `period` steps apart.
+        let mut tortoise = seed;
+        let mut hare = seed;
+        for _ in 0..period {
+            hare = step(hare);
+        }
+        let mut tail_length = 0;
+        while tortoise != hare {
+            tortoise = step(tortoise);
+            hare = step(hare);
+            tail_length += 1;
+        }
+        Some((tail_length, period))
+    }
+
+    /// Iterates `step` from `seed` for at most `steps` steps, stopping before the
+    /// first value that has already been visited.
+    fn trace_orbit(seed: u64, steps: usize, step: impl Fn(u64) -> u64) -> Vec<u64> {
+        let mut seen = HashSet::from([seed]);
+        let mut trajectory = vec![seed];
+        let mut current = seed;
+        for _ in 0..steps {
+            current = step(current);
+            if !seen.insert(current) { break; }
+            trajectory.push(current);
+        }
+        trajectory
+    }
+
+    /// A moving-origin walk: feeds primes through a `MomaRing` and analyzes the
+    /// resulting signature history.
+    ///
+    /// This generalizes the common "feed primes, collect signatures, analyze" loop,
+    /// offering entropy, drift, and autocorrelation over the recorded history.
+    pub struct MomaWalk<S: OriginStrategy> {
+        ring: MomaRing<S>,
+        history: Vec<u64>,
+    }
+
+    impl<S: OriginStrategy> MomaWalk<S> {
+        /// Creates a new, empty `MomaWalk` for a given modulus and strategy.
+        ///
+        /// # Parameters
+        /// - `modulus`: The modulus for the internal `MomaRing`.
+        /// - `strategy`: An instance of a struct that implements `OriginStrategy`.
+        pub fn new(modulus: u64, strategy: S) -> Self {
+            Self {
+                ring: MomaRing::new(modulus, strategy),
+                history: Vec::new(),
+            }
+        }
+
+        /// Calculates the signature of the next prime in the walk and records it.
+        ///
+        /// # Returns
+        /// The calculated signature for the prime `p`.
+        pub fn next(&mut self, p: u64) -> u64 {
+            let signature = self.ring.signature(p);
+            self.history.push(signature);
+            signature
+        }
+
+        /// Feeds every prime from an iterator into the walk.
+        pub fn walk<I>(&mut self, primes: I)
+        where
+            I: IntoIterator<Item = u64>,
+        {
+            for p in primes {
+                self.next(p);
+            }
+        }
+
+        /// Returns a slice of the recorded signature history.
+        pub fn history(&self) -> &[u64] {
+            &self.history
+        }
+
+        /// Calculates the Shannon entropy (in bits) of the recorded signatures.
+        pub fn entropy(&self) -> f64 {
+            let mut entropy = Entropy::new();
+            entropy.add_all(self.history.iter().copied());
+            entropy.total_entropy()
+        }
+
+        /// Calculates the average absolute difference between consecutive signatures,
+        /// exactly as `OriginDrift::drift_magnitude` does.
+        pub fn drift_magnitude(&self) -> f64 {
+            origin_drift::mean_abs_delta(&self.history)
+        }
+
+        /// Calculates the normalized autocorrelation of the signature history at lags
+        /// `0..=max_lag`, using `score::autocorrelation`.
+        pub fn autocorrelation(&self, max_lag: usize) -> Vec<f64> {
+            let series: Vec<f64> = self.history.iter().map(|&s| s as f64).collect();
+            score::autocorrelation(&series, max_lag)
+        }
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{CompositeMass, Fixed, PrimeGap};
+
+    #[test]
+    fn test_uniformity_score() {
+        let primes: Vec<u64> = primes::primes_in_range(5, 2000);
+        // p + prev_prime(p) is even for odd primes, so Fixed(0) mod 2 is constant.
+        let constant = MomaRing::new(2, Fixed(0));
+        assert_eq!(constant.uniformity_score(&primes), 0.0);
+        let varied = MomaRing::new(2, CompositeMass);
+        assert!(varied.uniformity_score(&primes) > 0.9);
+
+        let score = MomaRing::new(16, PrimeGap).uniformity_score(&primes);
+        assert!(score > 0.0 && score <= 1.0);
+        assert_eq!(MomaRing::new(1, PrimeGap).uniformity_score(&primes), 0.0);
+        assert_eq!(MomaRing::new(16, PrimeGap).uniformity_score(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_preimages() {
+        let ring = MomaRing::new(10, PrimeGap);
+        let target = ring.signature(13);
+        let found = ring.preimages(target, 2, 200);
+        assert!(found.contains(&13));
+        assert!(found.iter().all(|&p| ring.signature(p) == target));
+        assert!(ring.preimages(target, 200, 2).is_empty());
+    }
+
+    #[test]
+    fn test_batch_signatures_match_individual_calls() {
+        let ring = MomaRing::new(37, CompositeMass);
+        let primes = primes::primes_in_range(2, 500);
+        let expected: Vec<u64> = primes.iter().map(|&p| ring.signature(p)).collect();
+        assert_eq!(ring.signatures(&primes), expected);
+        assert_eq!(ring.signatures(&primes[10..20]), expected[10..20]);
+        assert!(ring.signatures(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_signature_with_custom_input() {
+        let ring = MomaRing::new(50, PrimeGap);
+        let predecessor = ring.signature_with(29, |p| p + primes::prev_prime(p));
+        assert_eq!(predecessor, ring.signature(29));
+        let successor = ring.signature_with(29, |p| p + primes::next_prime(p));
+        assert_eq!(successor, (29 + 31 + 6) % 50);
+        assert_eq!(ring.signature_with(29, |p| 2 * p), (58 + 6) % 50);
+    }
+
+    #[test]
+    fn test_moma_walk_analyses() {
+        // Signatures of (3, 5, 7, 11, 13) under PrimeGap mod 10:
+        // 3+2+1=6, 5+3+2=10, 7+5+2=14, 11+7+4=22, 13+11+2=26 -> [6, 0, 4, 2, 6].
+        let mut walk = MomaWalk::new(10, PrimeGap);
+        walk.walk([3, 5, 7, 11, 13]);
+        assert_eq!(walk.history(), &[6, 0, 4, 2, 6]);
+
+        // Symbols {6, 6, 0, 4, 2}: H = -(0.4 log2 0.4 + 3 * 0.2 log2 0.2).
+        let expected_entropy = -(0.4f64 * 0.4f64.log2() + 3.0 * 0.2 * 0.2f64.log2());
+        assert!((walk.entropy() - expected_entropy).abs() < 1e-12);
+
+        // Deltas |0-6|, |4-0|, |2-4|, |6-2| = 6, 4, 2, 4 -> mean 4.
+        assert_eq!(walk.drift_magnitude(), 4.0);
+        let mut drift = crate::origin_drift::OriginDrift::new(10, PrimeGap);
+        for p in [3, 5, 7, 11, 13] {
+            drift.next(p);
+        }
+        assert_eq!(walk.drift_magnitude(), drift.drift_magnitude());
+
+        let correlations = walk.autocorrelation(2);
+        assert_eq!(correlations.len(), 3);
+        assert!((correlations[0] - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_orbit() {
+        let ring = MomaRing::new(10, PrimeGap);
+        let orbit = ring.orbit(13, 5);
+        assert_eq!(orbit.len(), 6);
+        assert_eq!(orbit[0], 13);
+        // signature(13) = 26 mod 10 = 6, so the next prime after 19 is 23.
+        assert_eq!(orbit[1], 23);
+        for w in orbit.windows(2) {
+            assert_eq!(w[1], primes::next_prime(w[0] + ring.signature(w[0])));
         }
+        assert_eq!(ring.orbit(13, 0), vec![13]);
+    }
+
+    #[test]
+    fn test_orbit_truncates_at_cycle() {
+        // 1 -> 2 -> 3 -> 4 -> 2: the repeat of 2 ends the trajectory.
+        let step = |x: u64| if x == 4 { 2 } else { x + 1 };
+        assert_eq!(trace_orbit(1, 10, step), vec![1, 2, 3, 4]);
+        assert_eq!(trace_orbit(1, 2, step), vec![1, 2, 3]);
+        assert_eq!(trace_orbit(7, 10, |x| x), vec![7]);
+    }
+
+    #[test]
+    fn test_find_cycle() {
+        // 1 -> 2 -> 3 -> 4 -> 2: a tail of 1 step into a 3-cycle.
+        let step = |x: u64| if x == 4 { 2 } else { x + 1 };
+        assert_eq!(brent_cycle(1, 100, step), Some((1, 3)));
+        assert_eq!(brent_cycle(2, 100, step), Some((0, 3)));
+        assert_eq!(brent_cycle(7, 100, |x| x), Some((0, 1)));
+        // x -> x² + 1 mod 255 from 3: 3, 10, 101, 2, 5, 26, 167, 95, 101, ...
+        assert_eq!(brent_cycle(3, 100, |x| (x * x + 1) % 255), Some((2, 6)));
+        assert_eq!(brent_cycle(1, 2, step), None);
+        assert_eq!(brent_cycle(1, 0, |x| x), None);
+
+        // The orbit map is strictly increasing, so no cycle is ever found.
+        let ring = MomaRing::new(7, PrimeGap);
+        assert_eq!(ring.find_cycle(11, 1_000), None);
     }
 
+    #[test]
+    fn test_residue_centered() {
+        // Fixed(3) origin, modulus 10: residue adds 3, residue_centered subtracts it.
+        let ring = MomaRing::new(10, Fixed(3));
+        assert_eq!(ring.residue(8, 7), 1);
+        assert_eq!(ring.residue_centered(8, 7), 5);
+        assert_eq!(ring.residue_centered(9, 7), -4);
+        assert_eq!(ring.residue_centered(3, 7), 0);
+        assert_eq!(ring.residue_centered(2, 7), -1);
+        assert_eq!(ring.residue_centered(13, 7), 0);
+
+        // Odd modulus: the range is [-3, 3].
+        let odd = MomaRing::new(7, Fixed(0));
+        let centered: Vec<i64> = (0..7).map(|v| odd.residue_centered(v, 2)).collect();
+        assert_eq!(centered, vec![0, 1, 2, 3, -3, -2, -1]);
+
+        // Values and origins near u64::MAX must not overflow.
+        let wide = MomaRing::new(u64::MAX, Fixed(1));
+        assert_eq!(wide.residue_centered(u64::MAX - 1, 2), -2);
+
+        let unreduced = MomaRing::new(0, Fixed(5));
+        assert_eq!(unreduced.residue(3, 7), 3);
+        assert_eq!(unreduced.residue_centered(3, 7), -2);
+        assert_eq!(unreduced.residue_centered(12, 7), 7);
+    }
+
+    #[test]
+    fn test_preimages_with_zero_modulus() {
+        let ring = MomaRing::new(0, Fixed(0));
+        // Without a modulus the signature of 13 is simply 13 + 11.
+        assert_eq!(ring.preimages(24, 2, 100), vec![13]);
+    }
+}
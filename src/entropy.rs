@@ -38,18 +38,72 @@ impl<T: Eq + Hash> Entropy<T> {
         }
     }
 
+    /// Removes one occurrence of an item from the sequence, e.g. the oldest item
+    /// leaving a sliding window.
+    ///
+    /// # Returns
+    /// `true` if the item was present and removed, `false` if it was not found.
+    pub fn remove(&mut self, item: &T) -> bool {
+        let Some(count) = self.frequencies.get_mut(item) else {
+            return false;
+        };
+        *count -= 1;
+        if *count == 0 {
+            self.frequencies.remove(item);
+        }
+        self.count -= 1;
+        true
+    }
+
+    /// Returns the number of occurrences of each distinct item seen so far.
+    pub fn frequencies(&self) -> &HashMap<T, u64> {
+        &self.frequencies
+    }
+
+    /// Returns the empirical probability of `item`, i.e. its share of all items seen.
+    /// Returns `0.0` for unseen items, or if no items have been added.
+    pub fn probability(&self, item: &T) -> f64 {
+        match self.frequencies.get(item) {
+            Some(&count) => count as f64 / self.count as f64,
+            None => 0.0,
+        }
+    }
+
+    /// Returns the total number of items seen so far, counting repeats.
+    pub fn len(&self) -> u64 {
+        self.count
+    }
+
+    /// Returns `true` if no items have been added.
+    pub fn is_empty(&self) -> bool {
+        self.count == 0
+    }
+
     /// Calculates the total Shannon entropy of the distribution of items seen so far.
     ///
     /// The formula used is H(X) = -Σ [P(x) * log₂(P(x))] for all x in X.
     ///
     /// # Returns
-    /// The total entropy as an `f64`. Returns `0.0` if no items have been added.
+    /// The total entropy in bits as an `f64`. Returns `0.0` if no items have been added.
     pub fn total_entropy(&self) -> f64 {
+        self.entropy_base(2.0)
+    }
+
+    /// Calculates the Shannon entropy in an arbitrary logarithm base,
+    /// H(X) = -Σ [P(x) * log_b(P(x))], e.g. base 2 for bits, `e` for nats or 10 for dits.
+    ///
+    /// Returns `0.0` if no items have been added.
+    ///
+    /// # Panics
+    /// Panics if `base` is not positive or is exactly 1.
+    pub fn entropy_base(&self, base: f64) -> f64 {
+        assert!(base > 0.0 && base != 1.0, "Logarithm base must be positive and not 1.");
         if self.count == 0 {
             return 0.0;
         }
 
-        self.frequencies
+        let bits: f64 = self
+            .frequencies
             .values()
             .map(|&count| {
                 let probability = count as f64 / self.count as f64;
@@ -59,10 +113,270 @@ impl<T: Eq + Hash> Entropy<T> {
                     0.0
                 }
             })
+            .sum();
+        // Change of base from bits; exact for base 2.
+        bits / base.log2()
+    }
+
+    /// Calculates the Shannon entropy in nats (natural logarithm), as commonly quoted
+    /// in the physics and statistics literature. Returns `0.0` if no items have been added.
+    pub fn entropy_nats(&self) -> f64 {
+        self.entropy_base(std::f64::consts::E)
+    }
+
+    /// Calculates each symbol's contribution `-P(x) * log₂(P(x))` to the total entropy.
+    ///
+    /// The values sum to `total_entropy()`, so this shows which symbols dominate the
+    /// uncertainty. Returns an empty map if no items have been added.
+    pub fn per_symbol_entropy(&self) -> HashMap<&T, f64> {
+        self.frequencies
+            .iter()
+            .map(|(item, &count)| {
+                let probability = count as f64 / self.count as f64;
+                (item, -probability * probability.log2())
+            })
+            .collect()
+    }
+
+    /// Calculates the min-entropy, `-log₂(max P(x))`, of the distribution seen so far.
+    ///
+    /// This is the most conservative entropy measure, determined solely by the most
+    /// likely symbol, and is the standard estimate of guessing resistance for
+    /// cryptographic material. Returns `0.0` if no items have been added.
+    pub fn min_entropy(&self) -> f64 {
+        match self.frequencies.values().max() {
+            Some(&max_count) => -(max_count as f64 / self.count as f64).log2(),
+            None => 0.0,
+        }
+    }
+
+    /// Calculates the Rényi entropy of order `alpha`:
+    /// H_α(X) = log₂(Σ P(x)^α) / (1 - α).
+    ///
+    /// Special orders are handled by their limits:
+    /// - `alpha == 0.0`: the Hartley entropy, `log₂(distinct symbols)`.
+    /// - `alpha == 1.0`: the Shannon entropy, `total_entropy()`.
+    /// - `alpha == f64::INFINITY`: the min-entropy, `min_entropy()`.
+    ///
+    /// Rényi entropy is non-increasing in `alpha`. Returns `0.0` if no items have
+    /// been added.
+    ///
+    /// # Panics
+    /// Panics if `alpha` is negative or NaN.
+    pub fn renyi_entropy(&self, alpha: f64) -> f64 {
+        assert!(alpha >= 0.0, "Rényi order must be non-negative.");
+        if self.count == 0 {
+            return 0.0;
+        }
+        if alpha == 0.0 {
+            return (self.frequencies.len() as f64).log2();
+        }
+        if alpha == 1.0 {
+            return self.total_entropy();
+        }
+        if alpha.is_infinite() {
+            return self.min_entropy();
+        }
+
+        let sum: f64 = self
+            .frequencies
+            .values()
+            .map(|&count| (count as f64 / self.count as f64).powf(alpha))
+            .sum();
+        sum.log2() / (1.0 - alpha)
+    }
+
+    /// Calculates the cross-entropy H(P, Q) = -Σ [P(x) * log₂(Q(x))] in bits, where `P`
+    /// is this distribution and `Q` is `other`.
+    ///
+    /// If some symbol seen here has never been seen by `other`, the cross-entropy is
+    /// `f64::INFINITY`: no finite code for `Q` can encode it. Returns `0.0` if no
+    /// items have been added to this distribution.
+    pub fn cross_entropy(&self, other: &Entropy<T>) -> f64 {
+        self.frequencies
+            .iter()
+            .map(|(item, &count)| {
+                let p = count as f64 / self.count as f64;
+                -p * other.probability(item).log2()
+            })
+            .sum()
+    }
+
+    /// Calculates the Kullback-Leibler divergence D(P ‖ Q) = Σ [P(x) * log₂(P(x) / Q(x))]
+    /// in bits, where `P` is this distribution and `Q` is `other`.
+    ///
+    /// The divergence is zero for identical distributions and is not symmetric. As with
+    /// `cross_entropy`, it is `f64::INFINITY` if some symbol seen here has never been
+    /// seen by `other`; symbols seen only by `other` contribute nothing. Returns `0.0`
+    /// if no items have been added to this distribution.
+    pub fn kl_divergence(&self, other: &Entropy<T>) -> f64 {
+        self.frequencies
+            .iter()
+            .map(|(item, &count)| {
+                let p = count as f64 / self.count as f64;
+                p * (p / other.probability(item)).log2()
+            })
             .sum()
     }
+
+    /// Calculates the Jensen-Shannon distance between this distribution and `other`:
+    /// the square root of JSD(P ‖ Q) = ½D(P ‖ M) + ½D(Q ‖ M) in bits, where
+    /// M = ½(P + Q).
+    ///
+    /// Unlike `kl_divergence`, this is symmetric, always finite and bounded to
+    /// `[0, 1]`: 0 for identical distributions and 1 for disjoint ones. It is also a
+    /// true metric. Returns `0.0` if both distributions are empty, and `1.0` if exactly
+    /// one of them is.
+    pub fn js_distance(&self, other: &Entropy<T>) -> f64 {
+        match (self.is_empty(), other.is_empty()) {
+            (true, true) => return 0.0,
+            (true, false) | (false, true) => return 1.0,
+            (false, false) => {}
+        }
+
+        // D(P ‖ M) only needs P's support, where M(x) >= P(x) / 2 > 0.
+        let half_kl = |from: &Entropy<T>, to: &Entropy<T>| -> f64 {
+            from.frequencies
+                .keys()
+                .map(|item| {
+                    let p = from.probability(item);
+                    let m = 0.5 * (p + to.probability(item));
+                    p * (p / m).log2()
+                })
+                .sum::<f64>()
+                * 0.5
+        };
+        let divergence = half_kl(self, other) + half_kl(other, self);
+        // Rounding can push the divergence fractionally outside [0, 1].
+        divergence.clamp(0.0, 1.0).sqrt()
+    }
+
+    /// Calculates the Shannon entropy normalized to `[0, 1]` by the maximum possible
+    /// entropy for the observed alphabet, `log₂(distinct symbols)`.
+    ///
+    /// This makes distributions over different alphabet sizes (e.g. signatures under
+    /// different moduli) comparable. Returns `0.0` if fewer than two distinct symbols
+    /// have been seen.
+    pub fn normalized_entropy(&self) -> f64 {
+        let distinct = self.frequencies.len();
+        if distinct < 2 {
+            return 0.0;
+        }
+        self.total_entropy() / (distinct as f64).log2()
+    }
+}
+
+
+impl Entropy<i64> {
+    /// Builds an entropy calculator over a continuous series by bucketing each value
+    /// into an integer bin, `round(value / bin_width)`.
+    ///
+    /// Bins are centred on multiples of `bin_width`, so a width of `0.001` groups values
+    /// approximately as `calculate_path_entropy` does by rounding to three decimals,
+    /// up to rounding at bin edges and signed zero.
+    /// Non-finite values are skipped.
+    ///
+    /// # Panics
+    /// Panics if `bin_width` is not positive and finite.
+    pub fn from_binned(values: &[f64], bin_width: f64) -> Self {
+        assert!(
+            bin_width > 0.0 && bin_width.is_finite(),
+            "Bin width must be positive and finite."
+        );
+        let mut entropy = Self::new();
+        entropy.add_all(
+            values
+                .iter()
+                .filter(|value| value.is_finite())
+                .map(|&value| (value / bin_width).round() as i64),
+        );
+        entropy
+    }
+}
+
+/// Detects sustained "chaos events" in a stream of entropy values.
+///
+/// An event starts when entropy rises above `high_threshold` and only ends once it
+/// drops below `low_threshold`. This hysteresis prevents a signal hovering around a
+/// single threshold from being reported as many separate events.
+#[derive(Debug, Clone)]
+pub struct EntropyMonitor {
+    /// Entropy above which a chaos event begins.
+    pub high_threshold: f64,
+    /// Entropy below which an ongoing chaos event ends.
+    pub low_threshold: f64,
+    in_chaos: bool,
+    event_count: usize,
+}
+
+impl EntropyMonitor {
+    /// Creates a new `EntropyMonitor` with separate start and end thresholds.
+    ///
+    /// # Panics
+    /// Panics if `low_threshold > high_threshold`.
+    pub fn new(high_threshold: f64, low_threshold: f64) -> Self {
+        assert!(
+            low_threshold <= high_threshold,
+            "The low threshold must not exceed the high threshold."
+        );
+        Self {
+            high_threshold,
+            low_threshold,
+            in_chaos: false,
+            event_count: 0,
+        }
+    }
+
+    /// Creates a monitor with a single threshold, i.e. plain up-crossing detection.
+    pub fn with_threshold(threshold: f64) -> Self {
+        Self::new(threshold, threshold)
+    }
+
+    /// Feeds the next entropy value to the monitor.
+    ///
+    /// # Returns
+    /// `true` if this value starts a new chaos event, `false` otherwise.
+    pub fn update(&mut self, entropy: f64) -> bool {
+        if self.in_chaos {
+            if entropy < self.low_threshold {
+                self.in_chaos = false;
+            }
+            false
+        } else if entropy > self.high_threshold {
+            self.in_chaos = true;
+            self.event_count += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Returns `true` while a chaos event is in progress.
+    pub fn is_in_chaos(&self) -> bool {
+        self.in_chaos
+    }
+
+    /// Returns the number of chaos events started so far.
+    pub fn event_count(&self) -> usize {
+        self.event_count
+    }
 }
 
+/// Calculates the conditional entropy H(Y | X) in bits over observed `(x, y)` pairs:
+/// the uncertainty remaining in `y` once `x` is known.
+///
+/// Computed as H(X, Y) - H(X). The result is `0.0` when `y` is fully determined by
+/// `x`, and equals H(Y) when the two are independent. Returns `0.0` for no pairs.
+pub fn conditional_entropy<X: Eq + Hash, Y: Eq + Hash>(pairs: &[(X, Y)]) -> f64 {
+    let mut joint = Entropy::new();
+    let mut given = Entropy::new();
+    for (x, y) in pairs {
+        joint.add((x, y));
+        given.add(x);
+    }
+    // Rounding can leave a tiny negative difference when Y is determined by X.
+    (joint.total_entropy() - given.total_entropy()).max(0.0)
+}
 
 pub fn calculate_path_entropy(sequence_of_angles: Vec<f64>) -> f64 {
     if sequence_of_angles.is_empty() {
@@ -84,7 +398,7 @@ pub fn calculate_path_entropy(sequence_of_angles: Vec<f64>) -> f64 {
     for count in counts.iter() {
         let probability = *count.1 as f64 / total_steps as f64;
         if probability > 0.0 {
-            entropy = entropy - (probability * probability.log2());
+            entropy -= probability * probability.log2();
         }
     }
     entropy
@@ -106,3 +420,229 @@ fn main() {
     println!("\nentropy: {}", calculate_path_entropy(seq));
 }
 */
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distribution_accessors() {
+        let mut entropy = Entropy::new();
+        assert!(entropy.is_empty());
+        assert_eq!(entropy.probability(&'a'), 0.0);
+
+        entropy.add_all("abracadabra".chars());
+        assert_eq!(entropy.len(), 11);
+        assert!(!entropy.is_empty());
+        assert_eq!(entropy.frequencies().len(), 5);
+        assert_eq!(entropy.frequencies()[&'a'], 5);
+        assert!((entropy.probability(&'b') - 2.0 / 11.0).abs() < 1e-12);
+        assert_eq!(entropy.probability(&'z'), 0.0);
+
+        let total: f64 = entropy.frequencies().keys().map(|c| entropy.probability(c)).sum();
+        assert!((total - 1.0).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_kl_divergence_and_cross_entropy() {
+        let mut p = Entropy::new();
+        p.add_all([1, 1, 2, 3]);
+        let mut same = Entropy::new();
+        same.add_all([3, 2, 1, 1, 1, 2, 3, 1]);
+        assert!(p.kl_divergence(&same).abs() < 1e-12);
+        assert!((p.cross_entropy(&same) - p.total_entropy()).abs() < 1e-12);
+
+        // P = (1/2, 1/2), Q = (1/4, 3/4): D(P‖Q) = ½log₂2 + ½log₂(2/3).
+        let mut fair = Entropy::new();
+        fair.add_all([0, 1]);
+        let mut biased = Entropy::new();
+        biased.add_all([0, 1, 1, 1]);
+        let expected = 0.5 * 2f64.log2() + 0.5 * (2.0f64 / 3.0).log2();
+        assert!((fair.kl_divergence(&biased) - expected).abs() < 1e-12);
+        assert!(fair.kl_divergence(&biased) != biased.kl_divergence(&fair));
+        let cross = fair.cross_entropy(&biased);
+        assert!((cross - (fair.total_entropy() + fair.kl_divergence(&biased))).abs() < 1e-12);
+
+        // Symbols missing from the reference distribution make the divergence infinite.
+        let mut zero_only = Entropy::new();
+        zero_only.add(0);
+        assert_eq!(fair.kl_divergence(&zero_only), f64::INFINITY);
+        assert_eq!(fair.cross_entropy(&zero_only), f64::INFINITY);
+        assert!(zero_only.kl_divergence(&fair).is_finite());
+        assert_eq!(Entropy::new().kl_divergence(&fair), 0.0);
+    }
+
+    #[test]
+    fn test_js_distance() {
+        let mut p = Entropy::new();
+        p.add_all([1, 1, 2, 3]);
+        let mut same = Entropy::new();
+        same.add_all([3, 1, 2, 1, 1, 3, 2, 1]);
+        assert!(p.js_distance(&same) < 1e-6);
+
+        let mut disjoint = Entropy::new();
+        disjoint.add_all([4, 5, 5]);
+        assert!((p.js_distance(&disjoint) - 1.0).abs() < 1e-12);
+
+        // P = (1, 0), Q = (1/2, 1/2): JSD = ½log₂(4/3) + ¼log₂(2/3) + ¼log₂2.
+        let mut certain = Entropy::new();
+        certain.add(0);
+        let mut fair = Entropy::new();
+        fair.add_all([0, 1]);
+        let jsd = 0.5 * (4.0f64 / 3.0).log2() + 0.25 * (2.0f64 / 3.0).log2() + 0.25;
+        assert!((certain.js_distance(&fair) - jsd.sqrt()).abs() < 1e-12);
+        assert_eq!(certain.js_distance(&fair), fair.js_distance(&certain));
+
+        assert_eq!(Entropy::<u8>::new().js_distance(&Entropy::new()), 0.0);
+        assert_eq!(Entropy::new().js_distance(&fair), 1.0);
+    }
+
+    #[test]
+    fn test_conditional_entropy() {
+        // Each pair is (x, x % 3) for x in 0..5, so y is fully determined by x.
+        let determined: Vec<(u64, u64)> = (0..30).map(|x| (x % 5, x % 5 % 3)).collect();
+        assert!(conditional_entropy(&determined).abs() < 1e-12);
+
+        // Independent x and y: H(Y | X) = H(Y) = 1 bit.
+        let independent = [(0, 'a'), (0, 'b'), (1, 'a'), (1, 'b')];
+        assert!((conditional_entropy(&independent) - 1.0).abs() < 1e-12);
+
+        // Knowing x = 1 settles y, x = 0 leaves a fair coin: H(Y | X) = ½.
+        let partial = [(0, 'a'), (0, 'b'), (1, 'a'), (1, 'a')];
+        assert!((conditional_entropy(&partial) - 0.5).abs() < 1e-12);
+
+        assert_eq!(conditional_entropy::<u8, u8>(&[]), 0.0);
+    }
+
+    #[test]
+    fn test_from_binned_matches_path_entropy() {
+        let angles = vec![0.1234, 0.1231, 1.5, 1.5001, 2.25, 2.2504, 3.0, 0.1236, f64::NAN];
+        let finite: Vec<f64> = angles.iter().copied().filter(|a| a.is_finite()).collect();
+        let binned = Entropy::from_binned(&angles, 0.001);
+        assert_eq!(binned.len(), 8);
+        assert!((binned.total_entropy() - calculate_path_entropy(finite)).abs() < 1e-12);
+
+        // Coarser bins merge nearby values: {-0.4, 0.3} -> 0, {0.6, 1.4} -> 1.
+        let coarse = Entropy::from_binned(&[-0.4, 0.3, 0.6, 1.4], 1.0);
+        assert_eq!(coarse.frequencies()[&0], 2);
+        assert_eq!(coarse.frequencies()[&1], 2);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_from_binned_rejects_zero_width() {
+        Entropy::from_binned(&[1.0], 0.0);
+    }
+
+    #[test]
+    fn test_entropy_units() {
+        let mut entropy = Entropy::new();
+        entropy.add_all([1, 1, 1, 2, 3, 3]);
+        assert!((entropy.entropy_nats() - entropy.total_entropy() * 2f64.ln()).abs() < 1e-12);
+        assert_eq!(entropy.entropy_base(2.0), entropy.total_entropy());
+
+        let mut uniform = Entropy::new();
+        uniform.add_all(0..10);
+        assert!((uniform.entropy_base(10.0) - 1.0).abs() < 1e-12);
+        assert_eq!(Entropy::<u8>::new().entropy_nats(), 0.0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_entropy_base_rejects_base_one() {
+        let mut entropy = Entropy::new();
+        entropy.add(1);
+        entropy.entropy_base(1.0);
+    }
+
+    #[test]
+    fn test_normalized_entropy() {
+        let mut uniform = Entropy::new();
+        uniform.add_all([1, 2, 3, 4, 5]);
+        assert!((uniform.normalized_entropy() - 1.0).abs() < 1e-12);
+
+        let mut skewed = Entropy::new();
+        skewed.add_all([1, 1, 1, 2]);
+        let expected = skewed.total_entropy() / 2f64.log2();
+        assert!((skewed.normalized_entropy() - expected).abs() < 1e-12);
+        assert!(skewed.normalized_entropy() < 1.0);
+
+        let mut constant = Entropy::new();
+        constant.add_all([7, 7, 7]);
+        assert_eq!(constant.normalized_entropy(), 0.0);
+        assert_eq!(Entropy::<u64>::new().normalized_entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_remove_supports_sliding_window() {
+        let data = [1, 2, 2, 3, 1, 1, 4, 2, 3, 3];
+        let window = 4;
+        let mut sliding = Entropy::new();
+        for (i, &item) in data.iter().enumerate() {
+            sliding.add(item);
+            if i >= window {
+                assert!(sliding.remove(&data[i - window]));
+            }
+            let mut fresh = Entropy::new();
+            fresh.add_all(data[i.saturating_sub(window - 1)..=i].iter().copied());
+            assert!((sliding.total_entropy() - fresh.total_entropy()).abs() < 1e-12);
+        }
+        assert!(!sliding.remove(&99));
+    }
+
+    #[test]
+    fn test_renyi_and_min_entropy() {
+        let mut skewed = Entropy::new();
+        skewed.add_all([0, 0, 0, 0, 0, 1, 1, 2]);
+
+        let shannon = skewed.total_entropy();
+        let hartley = skewed.renyi_entropy(0.0);
+        let min = skewed.min_entropy();
+        assert!((min - -(5.0f64 / 8.0).log2()).abs() < 1e-12);
+        assert!(min <= shannon && shannon <= hartley);
+        assert!((hartley - 3f64.log2()).abs() < 1e-12);
+
+        assert_eq!(skewed.renyi_entropy(1.0), shannon);
+        assert_eq!(skewed.renyi_entropy(f64::INFINITY), min);
+        let collision = skewed.renyi_entropy(2.0);
+        assert!(min <= collision && collision <= shannon);
+
+        let mut uniform = Entropy::new();
+        uniform.add_all([1, 2, 3, 4]);
+        for alpha in [0.0, 0.5, 1.0, 2.0, f64::INFINITY] {
+            assert!((uniform.renyi_entropy(alpha) - 2.0).abs() < 1e-12);
+        }
+        assert_eq!(Entropy::<u8>::new().min_entropy(), 0.0);
+    }
+
+    #[test]
+    fn test_per_symbol_entropy_sums_to_total() {
+        let mut entropy = Entropy::new();
+        entropy.add_all(["a", "a", "b", "c", "c", "c"]);
+        let contributions = entropy.per_symbol_entropy();
+        assert_eq!(contributions.len(), 3);
+        let sum: f64 = contributions.values().sum();
+        assert!((sum - entropy.total_entropy()).abs() < 1e-12);
+        assert!((contributions[&"b"] - -(1.0f64 / 6.0) * (1.0f64 / 6.0).log2()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_entropy_monitor_hysteresis() {
+        // A sawtooth that repeatedly crosses 2.8 but never drops back below 1.5.
+        let series = [1.0, 2.0, 3.0, 2.5, 3.0, 2.5, 3.0, 2.5, 3.0];
+
+        let mut monitor = EntropyMonitor::new(2.8, 1.5);
+        let starts = series.iter().filter(|&&h| monitor.update(h)).count();
+        assert_eq!(starts, 1);
+        assert_eq!(monitor.event_count(), 1);
+        assert!(monitor.is_in_chaos());
+
+        monitor.update(1.0);
+        assert!(!monitor.is_in_chaos());
+
+        // Without hysteresis every up-crossing counts as a new event.
+        let mut single = EntropyMonitor::with_threshold(2.8);
+        let flaps = series.iter().filter(|&&h| single.update(h)).count();
+        assert_eq!(flaps, 4);
+    }
+}
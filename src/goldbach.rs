@@ -17,7 +17,7 @@ impl GoldbachProjector {
     /// Creates a new `GoldbachProjector` with a prime number database
     /// generated up to a specified limit.
     pub fn new(limit: u64) -> Self {
-        let prime_set = (2..=limit).filter(|&n| primes::is_prime(n)).collect();
+        let prime_set = primes::primes_in_range(2, limit).into_iter().collect();
         Self { prime_set }
     }
 
@@ -31,14 +31,81 @@ impl GoldbachProjector {
     /// # Returns
     /// A `Vec` of tuples `(p1, p2)`. Returns an empty vector if `n` is odd or too small.
     pub fn project(&self, n: u64) -> Vec<(u64, u64)> {
-        if n <= 2 || n % 2 != 0 {
-            return Vec::new();
+        self.pairs(n).collect()
+    }
+
+    /// Counts the Goldbach pairs of `n` without materializing them.
+    ///
+    /// Equivalent to `project(n).len()`. Returns 0 if `n` is odd or too small.
+    pub fn count(&self, n: u64) -> usize {
+        self.pairs(n).count()
+    }
+
+    /// Counts the Goldbach pairs of every even number in `[start, end]`, visiting
+    /// `start, start + step, ...` and skipping odd values.
+    ///
+    /// The result is exactly the data needed to plot a Goldbach comet.
+    ///
+    /// # Returns
+    /// A `Vec` of `(n, count)` tuples in ascending order of `n`.
+    ///
+    /// # Panics
+    /// Panics if `step` is zero.
+    pub fn counts_in_range(&self, start: u64, end: u64, step: u64) -> Vec<(u64, usize)> {
+        assert!(step > 0, "Step must be positive.");
+        (start..=end)
+            .step_by(step as usize)
+            .filter(|n| n.is_multiple_of(2))
+            .map(|n| (n, self.count(n)))
+            .collect()
+    }
+
+    /// Finds the Goldbach pair `(p1, p2)` of `n` with the smallest difference `p2 - p1`,
+    /// i.e. the pair nearest `n / 2`.
+    ///
+    /// The search walks outward from `n / 2` and stops at the first hit, which is
+    /// cheaper than calling `project` and scanning all pairs.
+    ///
+    /// # Returns
+    /// The balanced pair, or `None` if `n` is odd, too small, or has no pair in the prime set.
+    pub fn balanced_pair(&self, n: u64) -> Option<(u64, u64)> {
+        if n <= 2 || !n.is_multiple_of(2) {
+            return None;
         }
+        (2..=n / 2)
+            .rev()
+            .find(|p1| self.prime_set.contains(p1) && self.prime_set.contains(&(n - p1)))
+            .map(|p1| (p1, n - p1))
+    }
 
+    /// Finds the Goldbach pair of `n` that maximizes a caller-supplied score.
+    ///
+    /// For example, scoring with `|p1, p2| -((p2 - p1) as f64)` selects the most
+    /// central pair, while `|p1, p2| (p2 - p1) as f64` selects the most extreme one.
+    /// Ties are broken in favour of the pair with the smaller `p1`.
+    ///
+    /// # Parameters
+    /// - `n`: The even number to find the best Goldbach pair for.
+    /// - `score`: A function assigning a score to each pair `(p1, p2)` with `p1 <= p2`.
+    ///
+    /// # Returns
+    /// The highest-scoring pair, or `None` if `n` has no Goldbach pairs in the prime set.
+    pub fn best_pair(&self, n: u64, score: impl Fn(u64, u64) -> f64) -> Option<(u64, u64)> {
+        self.pairs(n)
+            .map(|(p1, p2)| ((p1, p2), score(p1, p2)))
+            .max_by(|(a, score_a), (b, score_b)| {
+                score_a.total_cmp(score_b).then_with(|| b.0.cmp(&a.0))
+            })
+            .map(|(pair, _)| pair)
+    }
+
+    /// Lazily yields the Goldbach pairs `(p1, p2)` of `n` with `p1 <= p2`.
+    fn pairs(&self, n: u64) -> impl Iterator<Item = (u64, u64)> + '_ {
+        let valid = n > 2 && n.is_multiple_of(2);
         self.prime_set
             .iter()
-            .filter(|&&p1| p1 <= n / 2) // Iterate up to n/2 to ensure unique pairs
-            .filter_map(|&p1| {
+            .filter(move |&&p1| valid && p1 <= n / 2) // Iterate up to n/2 to ensure unique pairs
+            .filter_map(move |&p1| {
                 let p2 = n - p1;
                 if self.prime_set.contains(&p2) {
                     Some((p1, p2))
@@ -46,6 +113,52 @@ impl GoldbachProjector {
                     None
                 }
             })
-            .collect()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_count_matches_project() {
+        let projector = GoldbachProjector::new(500);
+        for n in 0..=500 {
+            assert_eq!(projector.count(n), projector.project(n).len());
+        }
+
+        let comet = projector.counts_in_range(4, 20, 2);
+        assert_eq!(comet.first(), Some(&(4, 1)));
+        assert_eq!(comet.last(), Some(&(20, 2)));
+        assert_eq!(comet.len(), 9);
+        // An odd step alternates parity, so only the even values are reported.
+        let mixed = projector.counts_in_range(10, 20, 3);
+        assert_eq!(mixed, vec![(10, 2), (16, 2)]);
+    }
+
+    #[test]
+    fn test_balanced_pair() {
+        let projector = GoldbachProjector::new(1000);
+        assert_eq!(projector.balanced_pair(100), Some((47, 53)));
+        assert_eq!(projector.balanced_pair(4), Some((2, 2)));
+        assert_eq!(projector.balanced_pair(26), Some((13, 13)));
+        assert_eq!(projector.balanced_pair(101), None);
+        assert_eq!(projector.balanced_pair(2), None);
+        for n in (4..=1000).step_by(2) {
+            let scored = projector.best_pair(n, |p1, p2| -((p2 - p1) as f64));
+            assert_eq!(projector.balanced_pair(n), scored);
+        }
+    }
+
+    #[test]
+    fn test_best_pair() {
+        let projector = GoldbachProjector::new(200);
+        let closest = projector.best_pair(100, |p1, p2| -((p2 - p1) as f64));
+        assert_eq!(closest, Some((47, 53)));
+        let widest = projector.best_pair(100, |p1, p2| (p2 - p1) as f64);
+        assert_eq!(widest, Some((3, 97)));
+        assert_eq!(projector.best_pair(99, |_, _| 0.0), None);
+        // All scores tie, so the smallest p1 wins.
+        assert_eq!(projector.best_pair(100, |_, _| 1.0), Some((3, 97)));
+    }
+}
@@ -1,7 +1,31 @@
 //! Provides tools for modeling the "influence" of composite numbers.
 
 use crate::primes;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
+
+/// How a composite's influence falls off with distance from the measured point.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Falloff {
+    /// `mass / dist²`, a gravitational-style field. This is the default.
+    #[default]
+    InverseSquare,
+    /// `mass / dist`, a slower, longer-range falloff.
+    InverseLinear,
+    /// `mass * e^(-lambda * dist)`, a short-range, screened field.
+    Exponential { lambda: f64 },
+}
+
+impl Falloff {
+    /// Returns the weight applied to a mass at the given (non-negative) distance.
+    /// Distances are clamped to at least 1 for the inverse laws to avoid division by zero.
+    fn weight(&self, dist: f64) -> f64 {
+        match *self {
+            Falloff::InverseSquare => 1.0 / dist.powi(2).max(1.0),
+            Falloff::InverseLinear => 1.0 / dist.max(1.0),
+            Falloff::Exponential { lambda } => (-lambda * dist).exp(),
+        }
+    }
+}
 
 /// A tool to model the gravitational-like "influence" of composite numbers.
 ///
@@ -18,13 +42,50 @@ impl CompositeInfluence {
     /// Creates a new `CompositeInfluence` field for a given number range.
     /// The "mass" of each composite is calculated using `primes::prime_factor_mass`.
     pub fn new(range_start: u64, range_end: u64) -> Self {
+        let prime_set: HashSet<u64> = primes::primes_in_range(range_start, range_end)
+            .into_iter()
+            .collect();
         let composite_masses = (range_start..=range_end)
-            .filter(|&n| !primes::is_prime(n))
+            .filter(|n| !prime_set.contains(n))
             .map(|n| (n, primes::prime_factor_mass(n) as f64))
             .collect();
         Self { composite_masses }
     }
 
+    /// Creates a new `CompositeInfluence` like `new`, factoring the composites in
+    /// parallel across threads. The resulting masses are identical to `new`.
+    #[cfg(feature = "rayon")]
+    pub fn new_par(range_start: u64, range_end: u64) -> Self {
+        use rayon::prelude::*;
+
+        let prime_set: HashSet<u64> = primes::primes_in_range(range_start, range_end)
+            .into_iter()
+            .collect();
+        let composite_masses = (range_start..=range_end)
+            .into_par_iter()
+            .filter(|n| !prime_set.contains(n))
+            .map(|n| (n, primes::prime_factor_mass(n) as f64))
+            .collect();
+        Self { composite_masses }
+    }
+
+    /// Creates a `CompositeInfluence` from custom composite masses, e.g. masses
+    /// weighted by a different arithmetic function.
+    pub fn from_composites(composite_masses: HashMap<u64, f64>) -> Self {
+        Self { composite_masses }
+    }
+
+    /// Merges another field's composites into this one, e.g. to combine fields built
+    /// separately over adjacent ranges without recomputing them.
+    ///
+    /// Fields built by `new` assign every composite the same mass, so overlapping
+    /// ranges merge cleanly. If a composite appears in both fields with different
+    /// masses, the mass from `other` replaces this field's.
+    pub fn merge(&mut self, other: &CompositeInfluence) {
+        self.composite_masses
+            .extend(other.composite_masses.iter().map(|(&composite, &mass)| (composite, mass)));
+    }
+
     /// Calculates the total influence exerted by all composite masses at a given point.
     ///
     /// The influence of each composite number is weighted by the inverse square of its
@@ -33,13 +94,257 @@ impl CompositeInfluence {
     /// # Parameters
     /// - `point`: The number line coordinate to measure the influence at.
     pub fn influence_at_point(&self, point: f64) -> f64 {
+        self.influence_at_point_with(point, Falloff::InverseSquare)
+    }
+
+    /// Calculates the total influence at a point using the chosen distance falloff.
+    ///
+    /// # Parameters
+    /// - `point`: The number line coordinate to measure the influence at.
+    /// - `falloff`: The distance law used to weight each composite's mass.
+    pub fn influence_at_point_with(&self, point: f64, falloff: Falloff) -> f64 {
+        self.composite_masses
+            .iter()
+            .map(|(&composite, &mass)| mass * falloff.weight((point - composite as f64).abs()))
+            .sum()
+    }
+
+    /// Finds the single composite exerting the most inverse-square influence at a point.
+    ///
+    /// This explains which term dominates the total returned by `influence_at_point`.
+    ///
+    /// # Returns
+    /// `Some((composite, contribution))`, preferring the smallest composite on ties, or
+    /// `None` if the field holds no composites.
+    pub fn strongest_influencer_at(&self, point: f64) -> Option<(u64, f64)> {
+        self.composite_masses
+            .iter()
+            .map(|(&composite, &mass)| {
+                (composite, mass * Falloff::InverseSquare.weight((point - composite as f64).abs()))
+            })
+            .max_by(|a, b| a.1.total_cmp(&b.1).then(b.0.cmp(&a.0)))
+    }
+
+    /// Samples the inverse-square influence field at regular points from `start` to `end`.
+    ///
+    /// This is the recommended way to build a field curve, e.g. for plotting or for
+    /// feeding the influence values into `score::score_signal_to_noise`. Sampling is the
+    /// outer loop and composites the inner one, so each point is a single pass over the
+    /// composite map. Points are computed as `start + i * step` to avoid accumulating
+    /// rounding error, and `end` is included when it falls on the grid.
+    ///
+    /// # Returns
+    /// A `Vec` of `(point, influence)` pairs in increasing order of `point`, or an
+    /// empty vector if `end < start`.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive and finite.
+    pub fn influence_profile(&self, start: f64, end: f64, step: f64) -> Vec<(f64, f64)> {
+        assert!(step > 0.0 && step.is_finite(), "Step must be positive and finite.");
+        if end < start {
+            return Vec::new();
+        }
+        // A small tolerance keeps `end` on the grid despite floating-point division.
+        let samples = ((end - start) / step + 1e-9).floor() as usize + 1;
+        (0..samples)
+            .map(|i| {
+                let point = start + i as f64 * step;
+                (point, self.influence_at_point(point))
+            })
+            .collect()
+    }
+
+    /// Calculates the derivative of `influence_at_point` with respect to `point`.
+    ///
+    /// Each composite `c` contributes `-2 * mass / (point - c)³`. Within distance 1 of a
+    /// composite the inverse-square field is clamped flat, so that composite contributes 0.
+    pub fn influence_gradient_at(&self, point: f64) -> f64 {
+        self.composite_masses
+            .iter()
+            .map(|(&composite, &mass)| {
+                let delta = point - composite as f64;
+                if delta.abs() < 1.0 {
+                    0.0
+                } else {
+                    -2.0 * mass / delta.powi(3)
+                }
+            })
+            .sum()
+    }
+
+    /// Locates the stationary points of the influence field between `start` and `end`.
+    ///
+    /// The gradient is sampled every `step`, and each sign change between consecutive
+    /// non-zero samples is refined by linear interpolation. Both peaks of the field
+    /// (where the pull is strongest) and troughs (balance points between composites)
+    /// are returned; zero-gradient samples, such as the clamped plateaus around
+    /// composites, are skipped rather than reported.
+    ///
+    /// # Returns
+    /// The approximate locations in increasing order.
+    ///
+    /// # Panics
+    /// Panics if `step` is not positive and finite.
+    pub fn find_attractors(&self, start: f64, end: f64, step: f64) -> Vec<f64> {
+        assert!(step > 0.0 && step.is_finite(), "Step must be positive and finite.");
+        let mut attractors = Vec::new();
+        let mut last_nonzero: Option<(f64, f64)> = None;
+        let mut i = 0;
+        loop {
+            let point = start + i as f64 * step;
+            if point > end {
+                break;
+            }
+            let gradient = self.influence_gradient_at(point);
+            if gradient != 0.0 {
+                if let Some((prev_point, prev_gradient)) = last_nonzero
+                    && prev_gradient.signum() != gradient.signum()
+                {
+                    let t = prev_gradient / (prev_gradient - gradient);
+                    attractors.push(prev_point + t * (point - prev_point));
+                }
+                last_nonzero = Some((point, gradient));
+            }
+            i += 1;
+        }
+        attractors
+    }
+
+    /// Calculates the total influence at a point using Plummer-style softening.
+    ///
+    /// Each composite contributes `mass / (dist² + softening²)`. Unlike the hard
+    /// `max(dist², 1.0)` floor of `influence_at_point`, which makes the field flat
+    /// within distance 1 of every composite, softening keeps the field smooth while
+    /// still bounding it at the composites themselves. A softening of `0.0` gives the
+    /// raw inverse-square law, which is infinite exactly at a composite.
+    ///
+    /// # Parameters
+    /// - `point`: The number line coordinate to measure the influence at.
+    /// - `softening`: The softening length; larger values flatten the field near composites.
+    pub fn influence_at_point_softened(&self, point: f64, softening: f64) -> f64 {
+        let softening_sq = softening * softening;
         self.composite_masses
             .iter()
             .map(|(&composite, &mass)| {
-                // Use inverse square law for influence falloff
                 let dist_sq = (point - composite as f64).powi(2);
-                mass / dist_sq.max(1.0) // Avoid division by zero
+                mass / (dist_sq + softening_sq)
             })
             .sum()
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_softening_removes_hard_floor() {
+        let field = CompositeInfluence::new(8, 8); // A single composite, 8 = 2^3.
+
+        // The hard floor makes the field flat within distance 1.
+        assert_eq!(field.influence_at_point(8.25), field.influence_at_point(8.5));
+
+        let near = field.influence_at_point_softened(8.25, 0.5);
+        let far = field.influence_at_point_softened(8.5, 0.5);
+        assert!(near > far);
+
+        let at_composite = field.influence_at_point_softened(8.0, 0.5);
+        assert!(at_composite.is_finite());
+        assert!((at_composite - 3.0 / 0.25).abs() < 1e-12);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_construction_matches_sequential() {
+        let sequential = CompositeInfluence::new(1, 50_000);
+        let parallel = CompositeInfluence::new_par(1, 50_000);
+        assert_eq!(parallel.composite_masses, sequential.composite_masses);
+    }
+
+    #[test]
+    fn test_falloff_variants_differ() {
+        let field = CompositeInfluence::new(8, 8);
+        let square = field.influence_at_point_with(11.0, Falloff::InverseSquare);
+        let linear = field.influence_at_point_with(11.0, Falloff::InverseLinear);
+        assert_eq!(square, field.influence_at_point(11.0));
+        assert!((square - 3.0 / 9.0).abs() < 1e-12);
+        assert!((linear - 3.0 / 3.0).abs() < 1e-12);
+        assert_ne!(square, linear);
+
+        let screened = field.influence_at_point_with(11.0, Falloff::Exponential { lambda: 0.5 });
+        assert!((screened - 3.0 * (-1.5f64).exp()).abs() < 1e-12);
+
+        // Inverse laws are still clamped at the composite itself.
+        assert_eq!(field.influence_at_point_with(8.0, Falloff::InverseLinear), 3.0);
+    }
+
+    #[test]
+    fn test_merge_adjacent_ranges() {
+        let mut merged = CompositeInfluence::new(1, 50);
+        merged.merge(&CompositeInfluence::new(51, 100));
+        let whole = CompositeInfluence::new(1, 100);
+        assert_eq!(merged.composite_masses, whole.composite_masses);
+        assert!((merged.influence_at_point(42.5) - whole.influence_at_point(42.5)).abs() < 1e-12);
+
+        // Overlaps agree, and a custom field's mass wins where it differs.
+        merged.merge(&CompositeInfluence::new(40, 60));
+        assert_eq!(merged.composite_masses, whole.composite_masses);
+        merged.merge(&CompositeInfluence::from_composites(HashMap::from([(12, 10.0), (200, 1.0)])));
+        assert_eq!(merged.composite_masses[&12], 10.0);
+        assert_eq!(merged.composite_masses.len(), whole.composite_masses.len() + 1);
+    }
+
+    #[test]
+    fn test_strongest_influencer() {
+        let field = CompositeInfluence {
+            composite_masses: HashMap::from([(12, 4.0), (30, 8.0)]),
+        };
+        // Near 12 the lighter composite dominates: 4/2² = 1 versus 8/16² ≈ 0.03.
+        let (composite, contribution) = field.strongest_influencer_at(14.0).unwrap();
+        assert_eq!(composite, 12);
+        assert!((contribution - 1.0).abs() < 1e-12);
+        assert!(contribution < field.influence_at_point(14.0));
+        // Equidistant, the heavier one wins.
+        assert_eq!(field.strongest_influencer_at(21.0).unwrap().0, 30);
+
+        let twins = CompositeInfluence {
+            composite_masses: HashMap::from([(10, 1.0), (20, 1.0)]),
+        };
+        assert_eq!(twins.strongest_influencer_at(15.0).unwrap().0, 10);
+
+        let empty = CompositeInfluence { composite_masses: HashMap::new() };
+        assert_eq!(empty.strongest_influencer_at(5.0), None);
+    }
+
+    #[test]
+    fn test_influence_profile() {
+        let field = CompositeInfluence::new(4, 10);
+        let profile = field.influence_profile(4.0, 6.0, 0.5);
+        let points: Vec<f64> = profile.iter().map(|&(point, _)| point).collect();
+        assert_eq!(points, vec![4.0, 4.5, 5.0, 5.5, 6.0]);
+        for (point, influence) in profile {
+            assert_eq!(influence, field.influence_at_point(point));
+        }
+        assert!(field.influence_profile(6.0, 4.0, 0.5).is_empty());
+        assert_eq!(field.influence_profile(0.0, 0.3, 0.1).len(), 4);
+    }
+
+    #[test]
+    fn test_attractor_between_two_composites() {
+        let field = CompositeInfluence {
+            composite_masses: HashMap::from([(10, 1.0), (20, 1.0)]),
+        };
+        assert!(field.influence_gradient_at(14.0) < 0.0);
+        assert!(field.influence_gradient_at(16.0) > 0.0);
+        assert_eq!(field.influence_gradient_at(15.0), 0.0);
+
+        let attractors = field.find_attractors(12.0, 18.0, 0.75);
+        assert_eq!(attractors.len(), 1);
+        assert!((attractors[0] - 15.0).abs() < 1e-9);
+
+        // Analytic gradient agrees with a central difference away from the clamp.
+        let h = 1e-5;
+        let numeric = (field.influence_at_point(13.0 + h) - field.influence_at_point(13.0 - h)) / (2.0 * h);
+        assert!((numeric - field.influence_gradient_at(13.0)).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,185 @@
+//! A key derivation function (KDF) built on Moving Origin Modular Arithmetic.
+//!
+//! A KDF takes a low-entropy secret (like a password) and derives a fixed-length
+//! key from it. `MomaKdf` stretches the secret by chaining MOMA signatures, where
+//! each step depends on the previous residue, and hashes the chain with SHA-256.
+//!
+//! This is an experimental construction for exploring MOMA; it has not been
+//! analyzed for cryptographic strength and should not protect real secrets.
+
+use crate::core::{MomaRing, OriginStrategy};
+use crate::primes;
+use crate::strategy::PrimeGap;
+use sha2::{Digest, Sha256};
+
+/// A Key Derivation Function based on Moving Origin Modular Arithmetic.
+///
+/// The strategy used for the internal `MomaRing` is a type parameter, defaulting
+/// to `PrimeGap`. Different strategies derive different keys from the same inputs.
+pub struct MomaKdf<'a, S: OriginStrategy + Clone = PrimeGap> {
+    password: &'a [u8],
+    salt: &'a [u8],
+    iterations: u32,
+    output_len: usize,
+    strategy: S,
+}
+
+impl<'a> MomaKdf<'a> {
+    /// Configures a new MOMA KDF derivation using the `PrimeGap` strategy.
+    ///
+    /// # Parameters
+    /// - `password`: The secret input, typically a user's password.
+    /// - `salt`: A public, random value unique to each password.
+    /// - `iterations`: The work factor. Higher values are more secure but slower.
+    /// - `output_len`: The desired length of the final key in bytes, at most 32.
+    pub fn new(password: &'a [u8], salt: &'a [u8], iterations: u32, output_len: usize) -> Self {
+        Self::with_strategy(password, salt, iterations, output_len, PrimeGap)
+    }
+}
+
+impl<'a, S: OriginStrategy + Clone> MomaKdf<'a, S> {
+    /// Configures a new MOMA KDF derivation using the given origin strategy,
+    /// e.g. `strategy::CompositeMass`.
+    ///
+    /// The parameters are as for `new`.
+    pub fn with_strategy(
+        password: &'a [u8],
+        salt: &'a [u8],
+        iterations: u32,
+        output_len: usize,
+        strategy: S,
+    ) -> Self {
+        Self {
+            password,
+            salt,
+            iterations,
+            output_len,
+            strategy,
+        }
+    }
+
+    /// Derives the key.
+    ///
+    /// This is the core function that performs the key stretching. It uses MOMA's
+    /// signature function in a chained loop to create a computationally-intensive
+    /// process that cannot be parallelized.
+    ///
+    /// # Returns
+    /// The first `min(output_len, 32)` bytes of the final SHA-256 digest.
+    pub fn derive_key(&self) -> Vec<u8> {
+        // --- 1. Seeding Phase ---
+        // Use SHA-256 to deterministically generate MOMA parameters from the password and salt.
+        // This ensures that the same inputs always produce the same key.
+        let mut modulus_seed = Sha256::digest([self.password, self.salt].concat());
+        let mut prime_seed = Sha256::digest([self.salt, self.password].concat());
+
+        let modulus_u32 = u32::from_le_bytes(modulus_seed[..4].try_into().unwrap());
+        let prime_u32 = u32::from_le_bytes(prime_seed[..4].try_into().unwrap());
+        // The seeds are hashed straight from the password.
+        wipe(&mut modulus_seed);
+        wipe(&mut prime_seed);
+
+        let modulus = primes::next_prime(modulus_u32 as u64);
+        let mut current_prime = primes::next_prime(prime_u32 as u64);
+
+        // --- 2. Iteration Phase ---
+        // Each residue is fed straight into the running hash, so memory use does not
+        // grow with the iteration count. Hashing incrementally yields exactly the same
+        // digest as hashing the concatenated residues in one call.
+        let ring = MomaRing::new(modulus, self.strategy.clone());
+        let mut hasher = Sha256::new();
+
+        for _ in 0..self.iterations {
+            // Calculate the MOMA signature.
+            let residue = ring.signature(current_prime);
+            hasher.update(residue.to_le_bytes());
+
+            // Update the state in a dependent way. The next prime depends on the
+            // previous residue, creating a chain that cannot be parallelized.
+            current_prime = primes::next_prime(current_prime.wrapping_add(residue));
+        }
+
+        // --- 3. Finalization Phase ---
+        // The hash of the whole residue sequence mixes all the results together
+        // into a uniformly distributed output.
+        let mut final_hash = hasher.finalize();
+        let key = final_hash[..self.output_len.min(32)].to_vec();
+
+        // The full digest extends the key, so it is wiped rather than just dropped.
+        wipe(&mut final_hash);
+        key
+    }
+}
+
+/// Overwrites sensitive intermediate bytes when the `zeroize` feature is enabled,
+/// and does nothing otherwise.
+fn wipe(bytes: &mut [u8]) {
+    #[cfg(feature = "zeroize")]
+    zeroize::Zeroize::zeroize(bytes);
+    #[cfg(not(feature = "zeroize"))]
+    let _ = bytes;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::CompositeMass;
+
+    #[test]
+    fn test_derivation_is_deterministic() {
+        let first = MomaKdf::new(b"password", b"salt", 100, 32).derive_key();
+        let second = MomaKdf::new(b"password", b"salt", 100, 32).derive_key();
+        assert_eq!(first.len(), 32);
+        assert_eq!(first, second);
+        assert_eq!(MomaKdf::new(b"password", b"salt", 100, 16).derive_key(), first[..16]);
+    }
+
+    #[test]
+    fn test_derivation_is_sensitive_to_inputs() {
+        let key = MomaKdf::new(b"password", b"salt", 100, 32).derive_key();
+        // 'p' ^ 1 == 'q': a single flipped bit.
+        let flipped = MomaKdf::new(b"qassword", b"salt", 100, 32).derive_key();
+        assert_ne!(key, flipped);
+        assert_ne!(key, MomaKdf::new(b"password", b"pepper", 100, 32).derive_key());
+
+        let mass = MomaKdf::with_strategy(b"password", b"salt", 100, 32, CompositeMass).derive_key();
+        assert_ne!(key, mass);
+    }
+
+    /// The original buffered finalization: collect every residue, then hash once.
+    fn buffered_reference(password: &[u8], salt: &[u8], iterations: u32) -> Vec<u8> {
+        let modulus_seed = Sha256::digest([password, salt].concat());
+        let prime_seed = Sha256::digest([salt, password].concat());
+        let modulus = primes::next_prime(u32::from_le_bytes(modulus_seed[..4].try_into().unwrap()) as u64);
+        let mut current_prime = primes::next_prime(u32::from_le_bytes(prime_seed[..4].try_into().unwrap()) as u64);
+
+        let ring = MomaRing::new(modulus, PrimeGap);
+        let mut derived_bytes = Vec::new();
+        for _ in 0..iterations {
+            let residue = ring.signature(current_prime);
+            derived_bytes.extend_from_slice(&residue.to_le_bytes());
+            current_prime = primes::next_prime(current_prime.wrapping_add(residue));
+        }
+        Sha256::digest(&derived_bytes).to_vec()
+    }
+
+    #[cfg(feature = "zeroize")]
+    #[test]
+    fn test_wipe_zeroizes_buffers() {
+        let mut digest = Sha256::digest(b"password");
+        wipe(&mut digest);
+        assert!(digest.iter().all(|&b| b == 0));
+
+        // Wiping the intermediates does not change the derived key.
+        let key = MomaKdf::new(b"password", b"salt", 17, 32).derive_key();
+        assert_eq!(key, buffered_reference(b"password", b"salt", 17));
+    }
+
+    #[test]
+    fn test_streaming_matches_buffered_reference() {
+        for iterations in [0, 1, 2, 17, 64] {
+            let streamed = MomaKdf::new(b"password", b"salt", iterations, 32).derive_key();
+            assert_eq!(streamed, buffered_reference(b"password", b"salt", iterations));
+        }
+    }
+}
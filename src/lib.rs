@@ -13,32 +13,44 @@ pub mod core;
 pub mod entropy;
 pub mod goldbach;
 pub mod influence;
+#[cfg(feature = "kdf")]
+pub mod kdf;
 pub mod massfield;
 pub mod mutation;
 pub mod origin_drift;
+pub mod prelude;
+pub mod prime_gap;
 pub mod primes;
 pub mod resonance; // New
 pub mod strategy;
 pub mod score;
+pub mod smooth;
 pub mod barycentric; // New
 pub mod utils;
 
 // --- Public API Re-exports ---
 // This makes the most important structs directly accessible to users.
-pub use crate::core::{MomaRing, OriginStrategy};
-pub use crate::analysis::CompositeDampener;
-pub use crate::biosig::BioSigAnalyzer;
+pub use crate::core::{MomaRing, MomaWalk, OriginStrategy};
+pub use crate::analysis::{CompositeDampener, DampenStrategy, MassDampener};
+pub use crate::biosig::{BioSigAnalyzer, BioSigAnalyzerBuilder, BioSigError};
 pub use crate::composite_field::CompositeField;
-pub use crate::entropy::{Entropy, calculate_path_entropy, format_float_to_string};
+pub use crate::entropy::{Entropy, EntropyMonitor, calculate_path_entropy, conditional_entropy, format_float_to_string};
 pub use crate::goldbach::GoldbachProjector;
 pub use crate::influence::CompositeInfluence;
+#[cfg(feature = "kdf")]
+pub use crate::kdf::MomaKdf;
 pub use crate::massfield::MassField;
-pub use crate::origin_drift::OriginDrift;
-pub use crate::resonance::ResonanceFinder;
-pub use crate::score::{score_signal_to_noise, score_kurtosis};
-pub use crate::strategy::{Fixed, PrimeGap, CompositeMass};
-pub use crate::primes::{is_prime, next_prime, prev_prime, prime_factor_mass};
+// `prime_gap::PrimeGap` stays module-qualified; the root `PrimeGap` is the strategy.
+pub use crate::prime_gap::{PrimeGapError, PrimeGapField};
+pub use crate::origin_drift::{OriginDrift, rank_strategies};
+pub use crate::resonance::{AutocorrelationDetector, ResonanceCondition, ResonanceDetector, ResonanceFinder};
+#[cfg(feature = "rustfft")]
+pub use crate::resonance::SpectralDetector;
+pub use crate::score::{score_signal_to_noise, score_kurtosis, score_skewness, score_crest_factor, autocorrelation, chi_square_uniform, find_peaks};
+pub use crate::smooth::{exponential_moving_average, moving_average};
+pub use crate::strategy::{Fixed, PrimeGap, CompositeMass, DistinctCompositeMass, DivisorSum, Primorial, Sum, Scaled, Affine};
+pub use crate::primes::{is_prime, next_prime, prev_prime, prime_factor_mass, is_semiprime, is_k_almost_prime, factorize, omega, digit_sum, digital_root, primes_in_range, is_twin_prime, twin_primes_in_range, maximal_gaps_in_range, average_gap, expected_gap, prime_pi, nth_prime, FactorCache, euler_totient, mobius, sigma, primorial};
 pub use crate::mutation::{Mutation, MutationType};
-pub use crate::codon::{CodonTable};
-pub use crate::barycentric::{OriginShift};
-pub use crate::utils::write_csv;
+pub use crate::codon::{AminoClass, CodonTable, GeneticCode, ParseAminoAcidError, base_counts, gc_content, reverse_complement};
+pub use crate::barycentric::{LatticeOrigin, Moma2DRing, OriginShift, OriginShiftN};
+pub use crate::utils::{read_csv, write_csv, write_csv_columns};
@@ -30,25 +30,94 @@ impl MassField {
     /// - Returns a `Vec` of tuples, where each tuple contains the starting prime
     ///   and the calculated mass of the subsequent gap.
     pub fn generate_mass_map(&self) -> Vec<(u64, u64)> {
-        let mut map = Vec::new();
-        // Start with the first prime at or after the range_start.
-        let mut p = primes::next_prime(self.range_start.saturating_sub(1));
-
-        while p < self.range_end {
-            let p_next = primes::next_prime(p);
-            // Stop if the next prime goes beyond the desired range.
-            if p_next > self.range_end {
-                break;
-            }
-
-            let mass = (p + 1..p_next)
-                .filter(|&n| !primes::is_prime(n))
-                .map(primes::prime_factor_mass)
-                .sum();
-
-            map.push((p, mass));
-            p = p_next;
-        }
-        map
+        primes::primes_in_range(self.range_start, self.range_end)
+            .windows(2)
+            .map(|w| (w[0], (w[0] + 1..w[1]).map(primes::prime_factor_mass).sum()))
+            .collect()
+    }
+
+    /// Generates the cumulative composite mass along the prime axis.
+    ///
+    /// Each entry pairs a prime `p` with the total mass of all gaps from the start of
+    /// the range up to and including the gap after `p`, giving a non-decreasing
+    /// staircase suitable for plotting how composite mass accumulates.
+    pub fn cumulative_mass_curve(&self) -> Vec<(u64, u64)> {
+        self.generate_mass_map()
+            .into_iter()
+            .scan(0, |total, (p, mass)| {
+                *total += mass;
+                Some((p, *total))
+            })
+            .collect()
+    }
+
+    /// Generates the same map as `generate_mass_map`, summing each gap's composite
+    /// mass in parallel across threads. The result is in the same (ascending) order.
+    #[cfg(feature = "rayon")]
+    pub fn generate_mass_map_par(&self) -> Vec<(u64, u64)> {
+        use rayon::prelude::*;
+
+        primes::primes_in_range(self.range_start, self.range_end)
+            .par_windows(2)
+            .map(|w| (w[0], (w[0] + 1..w[1]).map(primes::prime_factor_mass).sum()))
+            .collect()
+    }
+
+    /// Generates a map of each prime to the composites in its following gap.
+    ///
+    /// This is the breakdown behind `generate_mass_map`: each entry pairs a prime `p`
+    /// with the `(composite, mass)` pairs for every composite in `(p, p_next)`, in
+    /// increasing order, so a heavy gap can be told apart as one highly composite
+    /// number or many lightly composite ones.
+    pub fn generate_detailed_map(&self) -> Vec<(u64, Vec<(u64, u64)>)> {
+        // Every number strictly between two consecutive primes is composite,
+        // so the gap masses can be computed directly without re-testing primality.
+        primes::primes_in_range(self.range_start, self.range_end)
+            .windows(2)
+            .map(|w| {
+                let composites = (w[0] + 1..w[1]).map(|c| (c, primes::prime_factor_mass(c))).collect();
+                (w[0], composites)
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detailed_map() {
+        let field = MassField::new(2, 30);
+        let detailed = field.generate_detailed_map();
+        let after_13 = detailed.iter().find(|(p, _)| *p == 13).unwrap();
+        assert_eq!(after_13.1, vec![(14, 2), (15, 2), (16, 4)]);
+
+        let totals: Vec<(u64, u64)> = detailed
+            .iter()
+            .map(|(p, composites)| (*p, composites.iter().map(|&(_, mass)| mass).sum()))
+            .collect();
+        assert_eq!(totals, field.generate_mass_map());
+        assert!(detailed.iter().find(|(p, _)| *p == 2).unwrap().1.is_empty());
+    }
+
+    #[test]
+    fn test_cumulative_mass_curve() {
+        let field = MassField::new(2, 100);
+        let curve = field.cumulative_mass_curve();
+        let map = field.generate_mass_map();
+        assert_eq!(curve.len(), map.len());
+        assert!(curve.windows(2).all(|w| w[0].1 <= w[1].1));
+        let total: u64 = map.iter().map(|&(_, mass)| mass).sum();
+        assert_eq!(curve.last().unwrap().1, total);
+        // 2 -> 3 has no composites; 3 -> 5 adds mass(4) = 2; 5 -> 7 adds mass(6) = 2.
+        assert_eq!(curve[..3], [(2, 0), (3, 2), (5, 4)]);
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_parallel_mass_map_matches_sequential() {
+        let field = MassField::new(1, 100_000);
+        assert_eq!(field.generate_mass_map_par(), field.generate_mass_map());
     }
 }
@@ -4,6 +4,7 @@ use crate::codon::AminoAcid;
 
 /// Represents the type of a point mutation's effect on the resulting amino acid.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum MutationType {
     /// The mutation does not change the amino acid.
     Silent,
@@ -11,10 +12,13 @@ pub enum MutationType {
     Missense,
     /// The mutation changes an amino acid codon to a stop codon.
     Nonsense,
+    /// An insertion or deletion shifts the downstream reading frame.
+    Frameshift,
 }
 
 /// Represents a single point mutation event.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Mutation {
     /// The original codon before mutation.
     pub original_codon: String,
@@ -61,4 +65,69 @@ impl Mutation {
             mutation_type,
         }
     }
-}
\ No newline at end of file
+
+    /// Creates a `Mutation` for an insertion or deletion that shifts the reading frame.
+    ///
+    /// The codons and amino acids describe the codon at the edit site before and after
+    /// the shift; the `mutation_type` is always `MutationType::Frameshift`.
+    pub fn frameshift(
+        original_codon: String,
+        mutated_codon: String,
+        original_amino_acid: AminoAcid,
+        mutated_amino_acid: AminoAcid,
+    ) -> Self {
+        Self {
+            original_codon,
+            mutated_codon,
+            original_amino_acid,
+            mutated_amino_acid,
+            mutation_type: MutationType::Frameshift,
+        }
+    }
+
+    /// Returns `true` if the substitution keeps the amino acid within the same
+    /// biochemical class (see `AminoAcid::property`), e.g. Leucine to Isoleucine.
+    ///
+    /// Silent mutations are trivially conservative; changes to or from a Stop signal
+    /// never are, unless both amino acids are Stop.
+    pub fn is_conservative(&self) -> bool {
+        self.original_amino_acid.property() == self.mutated_amino_acid.property()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn mutation(original: AminoAcid, mutated: AminoAcid) -> Mutation {
+        Mutation::new(String::new(), String::new(), original, mutated)
+    }
+
+    #[test]
+    fn test_is_conservative() {
+        let conservative = mutation(AminoAcid::Leucine, AminoAcid::Isoleucine);
+        assert_eq!(conservative.mutation_type, MutationType::Missense);
+        assert!(conservative.is_conservative());
+
+        let radical = mutation(AminoAcid::AsparticAcid, AminoAcid::Arginine);
+        assert_eq!(radical.mutation_type, MutationType::Missense);
+        assert!(!radical.is_conservative());
+
+        assert!(mutation(AminoAcid::Serine, AminoAcid::Serine).is_conservative());
+        assert!(!mutation(AminoAcid::Tryptophan, AminoAcid::Stop).is_conservative());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_round_trip() {
+        let original = Mutation::new("UGG".to_string(), "UAG".to_string(), AminoAcid::Tryptophan, AminoAcid::Stop);
+        let json = serde_json::to_string(&original).unwrap();
+        assert!(json.contains("\"original_amino_acid\":\"Tryptophan\""));
+        assert!(json.contains("\"mutation_type\":\"Nonsense\""));
+
+        let restored: Mutation = serde_json::from_str(&json).unwrap();
+        assert_eq!(restored.mutated_codon, "UAG");
+        assert_eq!(restored.original_amino_acid, AminoAcid::Tryptophan);
+        assert_eq!(restored.mutation_type, MutationType::Nonsense);
+    }
+}
@@ -12,7 +12,11 @@ use std::marker::PhantomData;
 /// This can be used to analyze the stability or chaotic nature of a given strategy.
 pub struct OriginDrift<S: OriginStrategy> {
     ring: MomaRing<S>,
+    /// Recorded signatures. In windowed mode this may hold up to twice the window,
+    /// of which only the last `window` entries are live; see `history()`.
     history: Vec<u64>,
+    /// The maximum number of recent signatures to retain, or `None` for unbounded.
+    window: Option<usize>,
     // PhantomData is used because S is part of the struct's logic but not a field.
     _strategy: PhantomData<S>,
 }
@@ -27,12 +31,36 @@ impl<S: OriginStrategy> OriginDrift<S> {
         Self {
             ring: MomaRing::new(modulus, strategy),
             history: Vec::new(),
+            window: None,
             _strategy: PhantomData,
         }
     }
 
+    /// Creates a new `OriginDrift` analyzer that retains only the `window` most
+    /// recent signatures, so that drift statistics reflect recent behavior.
+    ///
+    /// # Parameters
+    /// - `modulus`: The modulus for the internal `MomaRing`.
+    /// - `strategy`: An instance of a struct that implements `OriginStrategy`.
+    /// - `window`: The maximum number of signatures kept in the history. A window
+    ///   of 0 or 1 never holds a pair of signatures, so its drift is always 0.0.
+    pub fn with_window(modulus: u64, strategy: S, window: usize) -> Self {
+        Self {
+            window: Some(window),
+            ..Self::new(modulus, strategy)
+        }
+    }
+
+    /// Clears the recorded signature history.
+    pub fn reset(&mut self) {
+        self.history.clear();
+    }
+
     /// Calculates the MOMA signature for the next prime in a sequence and records it.
     ///
+    /// In windowed mode, the oldest signatures are dropped once the window is full.
+    /// Dropped signatures are compacted away in batches, so each call is amortized O(1).
+    ///
     /// # Parameters
     /// - `p`: The prime number to analyze.
     ///
@@ -41,6 +69,12 @@ impl<S: OriginStrategy> OriginDrift<S> {
     pub fn next(&mut self, p: u64) -> u64 {
         let signature = self.ring.signature(p);
         self.history.push(signature);
+        if let Some(window) = self.window
+            && self.history.len() > window.saturating_mul(2).max(1)
+        {
+            let excess = self.history.len() - window;
+            self.history.drain(..excess);
+        }
         signature
     }
 
@@ -52,20 +86,162 @@ impl<S: OriginStrategy> OriginDrift<S> {
     /// # Returns
     /// The average drift magnitude as an `f64`.
     pub fn drift_magnitude(&self) -> f64 {
-        if self.history.len() < 2 {
+        mean_abs_delta(self.history())
+    }
+
+    /// Calculates the (population) variance of the absolute differences between
+    /// consecutive signatures in the history.
+    ///
+    /// Where `drift_magnitude` measures how far signatures move on average, this
+    /// measures how uneven those moves are. Returns 0.0 if there's not enough history.
+    pub fn drift_variance(&self) -> f64 {
+        let history = self.history();
+        if history.len() < 2 {
             return 0.0;
         }
-        let deltas: Vec<f64> = self
-            .history
-            .windows(2)
-            .map(|w| (w[1] as i64 - w[0] as i64).abs() as f64)
-            .collect();
+        let mean = mean_abs_delta(history);
+        let deltas = history.windows(2).map(|w| w[1].abs_diff(w[0]) as f64);
+        let count = (history.len() - 1) as f64;
+
+        deltas.map(|d| (d - mean).powi(2)).sum::<f64>() / count
+    }
 
-        deltas.iter().sum::<f64>() / deltas.len() as f64
+    /// Returns the largest absolute difference between consecutive signatures,
+    /// or `None` if there are fewer than two signatures in the history.
+    pub fn max_delta(&self) -> Option<u64> {
+        self.history().windows(2).map(|w| w[1].abs_diff(w[0])).max()
     }
 
     /// Returns a slice of the recorded signature history.
     pub fn history(&self) -> &[u64] {
-        &self.history
+        let start = self.window.map_or(0, |window| self.history.len().saturating_sub(window));
+        &self.history[start..]
+    }
+}
+
+/// Ranks labelled strategies by volatility, most volatile first.
+///
+/// Each strategy is run through its own `OriginDrift` over the same `primes`, and the
+/// resulting drift magnitudes are sorted in descending order. Strategies with equal
+/// drift keep their input order.
+///
+/// # Parameters
+/// - `modulus`: The modulus shared by every strategy's `MomaRing`.
+/// - `primes`: The sequence of primes fed to each analyzer.
+/// - `strategies`: `(label, strategy)` pairs to compare.
+///
+/// # Returns
+/// A `Vec` of `(label, drift_magnitude)` sorted by drift magnitude, descending.
+pub fn rank_strategies(
+    modulus: u64,
+    primes: &[u64],
+    strategies: Vec<(&str, Box<dyn OriginStrategy>)>,
+) -> Vec<(String, f64)> {
+    let mut ranking: Vec<(String, f64)> = strategies
+        .into_iter()
+        .map(|(label, strategy)| {
+            let mut drift = OriginDrift::new(modulus, strategy);
+            for &p in primes {
+                drift.next(p);
+            }
+            (label.to_string(), drift.drift_magnitude())
+        })
+        .collect();
+    ranking.sort_by(|a, b| b.1.total_cmp(&a.1));
+    ranking
+}
+
+/// Calculates the average absolute difference between consecutive values of a
+/// signature history, or 0.0 if there are fewer than two values.
+pub(crate) fn mean_abs_delta(history: &[u64]) -> f64 {
+    if history.len() < 2 {
+        return 0.0;
+    }
+    let deltas: Vec<f64> = history
+        .windows(2)
+        .map(|w| w[1].abs_diff(w[0]) as f64)
+        .collect();
+
+    deltas.iter().sum::<f64>() / deltas.len() as f64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::primes;
+    use crate::strategy::{Fixed, PrimeGap, Scaled};
+
+    #[test]
+    fn test_drift_statistics() {
+        let drift = OriginDrift {
+            ring: MomaRing::new(100, Fixed(0)),
+            history: vec![0, 5, 5, 12],
+            window: None,
+            _strategy: PhantomData,
+        };
+        // Deltas are 5, 0, 7: mean 4, variance (1 + 16 + 9) / 3.
+        assert_eq!(drift.drift_magnitude(), 4.0);
+        assert!((drift.drift_variance() - 26.0 / 3.0).abs() < 1e-12);
+        assert_eq!(drift.max_delta(), Some(7));
+
+        let empty = OriginDrift::new(100, Fixed(0));
+        assert_eq!(empty.drift_variance(), 0.0);
+        assert_eq!(empty.max_delta(), None);
+    }
+
+    #[test]
+    fn test_windowed_drift_forgets_old_signatures() {
+        // Fixed(0) signatures are p + prev_prime(p): 5, 8, 12, 18, 24, ...
+        let mut drift = OriginDrift::with_window(1000, Fixed(0), 2);
+        for p in [3, 5, 7] {
+            drift.next(p);
+        }
+        assert_eq!(drift.history(), &[8, 12]);
+        assert_eq!(drift.drift_magnitude(), 4.0);
+        drift.next(11);
+        assert_eq!(drift.history(), &[12, 18]);
+        assert_eq!(drift.drift_magnitude(), 6.0);
+
+        drift.reset();
+        assert!(drift.history().is_empty());
+        assert_eq!(drift.drift_magnitude(), 0.0);
+
+        for window in [0, 1] {
+            let mut tiny = OriginDrift::with_window(1000, Fixed(0), window);
+            for p in [3, 5, 7, 11] {
+                tiny.next(p);
+            }
+            assert_eq!(tiny.history().len(), window);
+            assert_eq!(tiny.drift_magnitude(), 0.0);
+        }
+    }
+
+    #[test]
+    fn test_windowed_drift_matches_tail_of_unbounded() {
+        let mut windowed = OriginDrift::with_window(1000, PrimeGap, 5);
+        let mut unbounded = OriginDrift::new(1000, PrimeGap);
+        for p in primes::primes_in_range(3, 500) {
+            windowed.next(p);
+            unbounded.next(p);
+            let tail = &unbounded.history()[unbounded.history().len().saturating_sub(5)..];
+            assert_eq!(windowed.history(), tail);
+            assert!(windowed.history.len() <= 10);
+        }
+        assert_eq!(windowed.drift_magnitude(), mean_abs_delta(&unbounded.history()[unbounded.history().len() - 5..]));
+    }
+
+    #[test]
+    fn test_rank_strategies() {
+        let primes = primes::primes_in_range(3, 200);
+        let strategies: Vec<(&str, Box<dyn OriginStrategy>)> = vec![
+            ("constant", Box::new(Fixed(0))),
+            ("wild", Box::new(Scaled(PrimeGap, 37))),
+            ("mild", Box::new(PrimeGap)),
+        ];
+        let ranking = rank_strategies(1000, &primes, strategies);
+
+        let labels: Vec<&str> = ranking.iter().map(|(label, _)| label.as_str()).collect();
+        assert_eq!(labels, vec!["wild", "mild", "constant"]);
+        assert!(ranking.windows(2).all(|w| w[0].1 > w[1].1));
     }
 }
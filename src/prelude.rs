@@ -0,0 +1,58 @@
+//! A convenience module re-exporting the most commonly used MOMA items.
+//!
+//! ```
+//! use moma::prelude::*;
+//!
+//! let ring = MomaRing::new(10, PrimeGap);
+//! let signature = ring.signature(13);
+//! ```
+//!
+//! Traits such as `OriginStrategy` are included so that custom strategies can be
+//! implemented and used without any further imports.
+
+pub use crate::core::{MomaRing, MomaWalk, OriginStrategy};
+pub use crate::entropy::Entropy;
+pub use crate::goldbach::GoldbachProjector;
+pub use crate::origin_drift::OriginDrift;
+pub use crate::primes::{
+    digit_sum, digital_root, is_prime, next_prime, prev_prime, prime_factor_mass, primes_in_range,
+};
+pub use crate::resonance::ResonanceFinder;
+pub use crate::strategy::{CompositeMass, DistinctCompositeMass, Fixed, PrimeGap};
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+
+    /// A custom strategy defined using only prelude imports.
+    struct Doubled;
+    impl OriginStrategy for Doubled {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            2 * p
+        }
+    }
+
+    #[test]
+    fn test_prelude_covers_common_usage() {
+        let ring = MomaRing::new(10, PrimeGap);
+        assert_eq!(ring.signature(13), (13 + 11 + 2) % 10);
+
+        let custom = MomaRing::new(10, Doubled);
+        assert_eq!(custom.residue(1, 7), 5);
+
+        let mut entropy = Entropy::new();
+        entropy.add_all(primes_in_range(2, 30).into_iter().map(|p| p % 6));
+        assert!(entropy.total_entropy() > 0.0);
+
+        let finder = ResonanceFinder::new(10, Fixed(0), prime_factor_mass);
+        assert!(is_prime(next_prime(10)));
+        assert_eq!(finder.check_prime(11), Some((11 + 7) % 10));
+
+        let projector = GoldbachProjector::new(100);
+        assert!(!projector.project(10).is_empty());
+
+        let mut drift = OriginDrift::new(10, CompositeMass);
+        drift.next(prev_prime(20));
+        assert_eq!(drift.history().len(), 1);
+    }
+}
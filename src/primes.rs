@@ -1,17 +1,21 @@
 //! Utility functions for prime number operations.
+//!
+//! NOTE: For a high-performance production crate, consider replacing these
+//! with a dependency on a specialized library like `primal`
 
-/// NOTE: For a high-performance production crate, consider replacing these
-/// with a dependency on a specialized library like `primal`
- 
+use crate::score;
+use std::collections::HashMap;
+#[cfg(feature = "rand")]
+use std::ops::Range;
 
 /// A basic primality test.
     pub fn is_prime(n: u64) -> bool {
         if n < 2 { return false; }
         if n == 2 || n == 3 { return true; }
-        if n % 2 == 0 || n % 3 == 0 { return false; }
+        if n.is_multiple_of(2) || n.is_multiple_of(3) { return false; }
         let mut i = 5;
         while i * i <= n {
-            if n % i == 0 || n % (i + 2) == 0 {
+            if n.is_multiple_of(i) || n.is_multiple_of(i + 2) {
                 return false;
             }
             i += 6;
@@ -23,7 +27,7 @@
     pub fn next_prime(n: u64) -> u64 {
         if n < 2 { return 2; }
         // Start with the next odd number.
-        let mut x = if n % 2 == 0 { n + 1 } else { n + 2 };
+        let mut x = if n.is_multiple_of(2) { n + 1 } else { n + 2 };
         loop {
             if is_prime(x) {
                 return x;
@@ -46,22 +50,749 @@
         0
     }
 
-    /// Calculates the "mass" of a number, defined as the count of its prime factors
-    /// with multiplicity. For example, `prime_factor_mass(12) = mass(2*2*3) = 3`.
-    pub fn prime_factor_mass(n: u64) -> u64 {
-        if n < 2 { return 0; }
-        let mut count = 0;
+    /// Factorizes `n` into `(prime, exponent)` pairs in ascending order of prime.
+    /// For example, `factorize(12) = [(2, 2), (3, 1)]`.
+    ///
+    /// Returns an empty vector for `n < 2`.
+    pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+        let mut factors = Vec::new();
+        if n < 2 { return factors; }
         let mut temp_n = n;
         let mut factor = 2;
         while factor * factor <= temp_n {
-            while temp_n % factor == 0 {
-                count += 1;
+            let mut exponent = 0;
+            while temp_n.is_multiple_of(factor) {
+                exponent += 1;
                 temp_n /= factor;
             }
+            if exponent > 0 {
+                factors.push((factor, exponent));
+            }
             factor += 1;
         }
         if temp_n > 1 {
-            count += 1;
+            factors.push((temp_n, 1));
+        }
+        factors
+    }
+
+    /// Calculates the "mass" of a number, defined as the count of its prime factors
+    /// with multiplicity. For example, `prime_factor_mass(12) = mass(2*2*3) = 3`.
+    pub fn prime_factor_mass(n: u64) -> u64 {
+        factorize(n).iter().map(|(_, e)| *e as u64).sum()
+    }
+
+    /// Checks whether `n` is a `k`-almost-prime: a product of exactly `k` primes,
+    /// counted with multiplicity. For example `8 = 2*2*2` is 3-almost-prime, and the
+    /// 1-almost-primes are exactly the primes.
+    pub fn is_k_almost_prime(n: u64, k: u32) -> bool {
+        prime_factor_mass(n) == k as u64
+    }
+
+    /// Checks whether `n` is a semiprime, a product of exactly two (not necessarily
+    /// distinct) primes such as `15 = 3*5` or `9 = 3*3`.
+    pub fn is_semiprime(n: u64) -> bool {
+        is_k_almost_prime(n, 2)
+    }
+
+    /// A memoizing cache of `prime_factor_mass` results, for analyses that compute
+    /// the mass of the same numbers repeatedly.
+    ///
+    /// Optionally, a smallest-prime-factor sieve over `[0, limit]` (see `with_sieve`)
+    /// factors any `n <= limit` in `O(log n)` divisions instead of trial division.
+    #[derive(Debug, Clone, Default)]
+    pub struct FactorCache {
+        masses: HashMap<u64, u64>,
+        smallest_factor: Vec<u32>,
+    }
+
+    impl FactorCache {
+        /// Creates an empty cache without a sieve.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Creates a cache backed by a smallest-prime-factor sieve over `[0, limit]`.
+        ///
+        /// The sieve costs 4 bytes per number, so `limit` should match the range
+        /// actually being analyzed.
+        ///
+        /// # Panics
+        /// Panics if `limit` does not fit in a `u32`.
+        pub fn with_sieve(limit: u64) -> Self {
+            let limit = u32::try_from(limit).expect("Sieve limit must fit in a u32.") as usize;
+            let mut smallest_factor = vec![0u32; limit + 1];
+            for i in 2..=limit {
+                if smallest_factor[i] != 0 { continue; }
+                for j in (i..=limit).step_by(i) {
+                    if smallest_factor[j] == 0 { smallest_factor[j] = i as u32; }
+                }
+            }
+            Self { masses: HashMap::new(), smallest_factor }
+        }
+
+        /// Returns `prime_factor_mass(n)`, computing and remembering it on first use.
+        /// Numbers covered by the sieve are factored directly and not stored.
+        pub fn mass(&mut self, n: u64) -> u64 {
+            if n >= 2 && (n as usize) < self.smallest_factor.len() {
+                let mut remaining = n as usize;
+                let mut mass = 0;
+                while remaining > 1 {
+                    remaining /= self.smallest_factor[remaining] as usize;
+                    mass += 1;
+                }
+                return mass;
+            }
+            *self.masses.entry(n).or_insert_with(|| prime_factor_mass(n))
+        }
+    }
+
+    /// Counts the distinct prime factors of `n` (the ω function).
+    /// For example, `omega(12) = |{2, 3}| = 2`, while `prime_factor_mass(12) = 3`.
+    pub fn omega(n: u64) -> u64 {
+        factorize(n).len() as u64
+    }
+
+    /// Calculates Euler's totient φ(n): the count of integers in `[1, n]` coprime to `n`.
+    /// For example, `euler_totient(12) = |{1, 5, 7, 11}| = 4`.
+    ///
+    /// By convention `euler_totient(1) = 1`; `euler_totient(0)` returns 0.
+    pub fn euler_totient(n: u64) -> u64 {
+        if n == 0 { return 0; }
+        factorize(n).iter().fold(n, |phi, &(p, _)| phi / p * (p - 1))
+    }
+
+    /// Calculates the Möbius function μ(n): 0 if `n` has a squared prime factor,
+    /// otherwise `1` or `-1` for an even or odd number of prime factors.
+    /// For example, `mobius(30) = -1` and `mobius(12) = 0`.
+    ///
+    /// By convention `mobius(1) = 1`; `mobius(0)` returns 0.
+    pub fn mobius(n: u64) -> i8 {
+        if n == 0 { return 0; }
+        let factors = factorize(n);
+        if factors.iter().any(|&(_, e)| e > 1) { return 0; }
+        if factors.len().is_multiple_of(2) { 1 } else { -1 }
+    }
+
+    /// Calculates the sum of divisors σ(n), including 1 and `n` itself.
+    /// For example, `sigma(12) = 1 + 2 + 3 + 4 + 6 + 12 = 28`.
+    ///
+    /// `sigma(0)` returns 0. Values too large for a `u64` saturate at `u64::MAX`.
+    pub fn sigma(n: u64) -> u64 {
+        if n == 0 { return 0; }
+        let sum = factorize(n).iter().fold(1u128, |acc, &(p, e)| {
+            // σ(p^e) = 1 + p + ... + p^e
+            let p = p as u128;
+            let mut term = 1u128;
+            let mut power = 1u128;
+            for _ in 0..e {
+                power *= p;
+                term += power;
+            }
+            acc.saturating_mul(term)
+        });
+        u64::try_from(sum).unwrap_or(u64::MAX)
+    }
+
+    /// The largest `k` for which `primorial(k)` fits in a `u64`.
+    /// `primorial(15) = 2 · 3 · … · 47 = 614889782588491410`; multiplying by 53 overflows.
+    pub const MAX_PRIMORIAL_INDEX: u64 = 15;
+
+    /// `PRIMORIALS[k]` is the product of the first `k` primes, for `k` up to the cap.
+    const PRIMORIALS: [u64; MAX_PRIMORIAL_INDEX as usize + 1] = {
+        let primes = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+        let mut table = [1u64; MAX_PRIMORIAL_INDEX as usize + 1];
+        let mut i = 1;
+        while i < table.len() {
+            table[i] = table[i - 1] * primes[i - 1];
+            i += 1;
+        }
+        table
+    };
+
+    /// Calculates the primorial: the product of the first `k` primes.
+    /// For example, `primorial(3) = 2 · 3 · 5 = 30`, and `primorial(0) = 1`.
+    ///
+    /// Larger primorials overflow a `u64`, so `k` is clamped to `MAX_PRIMORIAL_INDEX`:
+    /// every `k >= 15` returns `primorial(15)`. Values come from a precomputed table.
+    pub fn primorial(k: u64) -> u64 {
+        PRIMORIALS[k.min(MAX_PRIMORIAL_INDEX) as usize]
+    }
+
+    /// Calculates the sum of the digits of `n` when written in the given `radix`.
+    /// For example, `digit_sum(255, 16) = 0xF + 0xF = 30`.
+    ///
+    /// # Panics
+    /// Panics if `radix < 2`.
+    pub fn digit_sum(n: u64, radix: u32) -> u64 {
+        assert!(radix >= 2, "Radix must be at least 2.");
+        let radix = radix as u64;
+        let mut sum = 0;
+        let mut temp_n = n;
+        while temp_n > 0 {
+            sum += temp_n % radix;
+            temp_n /= radix;
+        }
+        sum
+    }
+
+    /// Calculates the digital root of `n` in the given `radix`, i.e. the single digit
+    /// obtained by repeatedly summing digits. For example, `digital_root(12345, 10) = 6`.
+    ///
+    /// # Panics
+    /// Panics if `radix < 2`.
+    pub fn digital_root(n: u64, radix: u32) -> u64 {
+        assert!(radix >= 2, "Radix must be at least 2.");
+        if n == 0 { return 0; }
+        // Closed form: the digital root is congruent to n modulo (radix - 1).
+        1 + (n - 1) % (radix as u64 - 1)
+    }
+
+    /// Reverses the digits of `n` in the given `radix`, or `None` if the result overflows.
+    fn reverse_digits(n: u64, radix: u32) -> Option<u64> {
+        let radix = radix as u64;
+        let mut reversed: u64 = 0;
+        let mut temp_n = n;
+        while temp_n > 0 {
+            reversed = reversed.checked_mul(radix)?.checked_add(temp_n % radix)?;
+            temp_n /= radix;
+        }
+        Some(reversed)
+    }
+
+    /// Checks whether `n` is a prime that reads the same forwards and backwards in base 10,
+    /// e.g. `131`.
+    pub fn is_palindromic_prime(n: u64) -> bool {
+        is_prime(n) && reverse_digits(n, 10) == Some(n)
+    }
+
+    /// Checks whether `n` is an emirp: a prime whose base-10 digit reversal is a
+    /// *different* prime, e.g. `13` (since `31` is prime). Palindromic primes are not emirps.
+    pub fn is_emirp(n: u64) -> bool {
+        if !is_prime(n) { return false; }
+        match reverse_digits(n, 10) {
+            Some(reversed) => reversed != n && is_prime(reversed),
+            None => false,
+        }
+    }
+
+    /// Checks whether `p` is a member of a twin-prime pair, i.e. `p` is prime and
+    /// `p - 2` or `p + 2` is also prime. For example `5` is a twin prime of both `3` and `7`.
+    pub fn is_twin_prime(p: u64) -> bool {
+        is_prime(p) && ((p >= 2 && is_prime(p - 2)) || is_prime(p + 2))
+    }
+
+    /// Returns all twin-prime pairs `(p, p + 2)` with both members in `[start, end]`.
+    ///
+    /// Pairs may share a member, so both `(3, 5)` and `(5, 7)` are returned when in range.
+    pub fn twin_primes_in_range(start: u64, end: u64) -> Vec<(u64, u64)> {
+        let mut twins = Vec::new();
+        if start > end { return twins; }
+        let mut p = next_prime(start.saturating_sub(1));
+        while p <= end {
+            let q = next_prime(p);
+            if q > end { break; }
+            if q - p == 2 { twins.push((p, q)); }
+            p = q;
+        }
+        twins
+    }
+
+    /// Returns the record (maximal) prime gaps with both primes in `[start, end]`, as
+    /// `(start_prime, end_prime, gap_size)` triples in increasing order.
+    ///
+    /// Scanning upward, a gap is a record if it is strictly larger than every earlier
+    /// gap in the range, so the first gap is always a record. Note that records are
+    /// relative to `start`: they only match the classical maximal gaps when `start <= 2`.
+    pub fn maximal_gaps_in_range(start: u64, end: u64) -> Vec<(u64, u64, u64)> {
+        let mut records = Vec::new();
+        let mut best = 0;
+        for pair in primes_in_range(start, end).windows(2) {
+            let gap = pair[1] - pair[0];
+            if gap > best {
+                best = gap;
+                records.push((pair[0], pair[1], gap));
+            }
+        }
+        records
+    }
+
+    /// Returns the empirical mean gap between consecutive primes in `[start, end]`,
+    /// i.e. `(last - first) / (count - 1)`. Returns `0.0` if the range holds fewer
+    /// than two primes.
+    pub fn average_gap(start: u64, end: u64) -> f64 {
+        let primes = primes_in_range(start, end);
+        match (primes.first(), primes.last()) {
+            (Some(&first), Some(&last)) if primes.len() >= 2 => {
+                (last - first) as f64 / (primes.len() - 1) as f64
+            }
+            _ => 0.0,
+        }
+    }
+
+    /// Returns the expected gap near `p` predicted by the prime number theorem, `ln(p)`.
+    ///
+    /// Dividing a gap by this gives its *merit*, the standard normalization under the
+    /// Cramér model. Values below `p = 3` are not meaningful (`expected_gap(1) = 0`).
+    pub fn expected_gap(p: u64) -> f64 {
+        (p as f64).ln()
+    }
+
+    /// The number of integers sieved at once by `primes_in_range`.
+    const SEGMENT_SIZE: u64 = 1 << 16;
+
+    /// Generates all primes in the inclusive range `[start, end]` using a segmented
+    /// Sieve of Eratosthenes.
+    ///
+    /// The base primes up to `√end` are sieved once, then the window is processed in
+    /// fixed-size segments backed by a bitset, so memory stays bounded for wide ranges.
+    /// This is much faster than `(start..=end).filter(|&n| is_prime(n))`, which
+    /// repeats trial division for every candidate.
+    ///
+    /// When the window is narrower than `√end`, e.g. a few hundred numbers near
+    /// `10^18`, sieving the base primes would cost far more than the window itself,
+    /// so each candidate is tested with a deterministic Miller-Rabin test instead.
+    pub fn primes_in_range(start: u64, end: u64) -> Vec<u64> {
+        let start = start.max(2);
+        if start > end { return Vec::new(); }
+
+        let limit = end.isqrt() as usize;
+        if end - start < limit as u64 {
+            return (start..=end).filter(|&n| miller_rabin(n)).collect();
+        }
+
+        // 1. Sieve the base primes up to √end.
+        let mut base_composite = vec![false; limit + 1];
+        let mut base_primes = Vec::new();
+        for i in 2..=limit {
+            if base_composite[i] { continue; }
+            base_primes.push(i as u64);
+            for j in (i * i..=limit).step_by(i) {
+                base_composite[j] = true;
+            }
+        }
+
+        // 2. Sieve the window one segment at a time.
+        let mut result = Vec::new();
+        let mut low = start;
+        loop {
+            let high = low.saturating_add(SEGMENT_SIZE - 1).min(end);
+            let len = (high - low + 1) as usize;
+            let mut bits = vec![0u64; len.div_ceil(64)];
+
+            for &bp in &base_primes {
+                if bp * bp > high { break; }
+                // Start at the first multiple inside the segment, but never at bp itself.
+                // If that multiple overflows, it lies beyond `high` anyway.
+                let Some(first) = low.div_ceil(bp).checked_mul(bp) else { continue; };
+                let mut m = (bp * bp).max(first);
+                while m <= high {
+                    let idx = (m - low) as usize;
+                    bits[idx / 64] |= 1 << (idx % 64);
+                    m = match m.checked_add(bp) {
+                        Some(next) => next,
+                        None => break,
+                    };
+                }
+            }
+
+            result.extend(
+                (0..len)
+                    .filter(|&idx| bits[idx / 64] & (1 << (idx % 64)) == 0)
+                    .map(|idx| low + idx as u64),
+            );
+
+            if high == end { break; }
+            low = high + 1;
+        }
+        result
+    }
+
+    /// A deterministic Miller-Rabin primality test for any `u64`.
+    ///
+    /// The first twelve primes as witnesses are known to be sufficient for all
+    /// `n < 3.3 * 10^24`, so the result is exact. Products are taken in `u128`.
+    fn miller_rabin(n: u64) -> bool {
+        const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+        if n < 2 { return false; }
+        if let Some(&w) = WITNESSES.iter().find(|&&w| n.is_multiple_of(w)) {
+            return n == w;
+        }
+
+        let mul_mod = |a: u64, b: u64| (a as u128 * b as u128 % n as u128) as u64;
+        let pow_mod = |mut base: u64, mut exp: u64| {
+            let mut result = 1;
+            while exp > 0 {
+                if exp & 1 == 1 { result = mul_mod(result, base); }
+                base = mul_mod(base, base);
+                exp >>= 1;
+            }
+            result
+        };
+
+        // Write n - 1 = d * 2^s with d odd.
+        let s = (n - 1).trailing_zeros();
+        let d = (n - 1) >> s;
+        WITNESSES.iter().all(|&a| {
+            let mut x = pow_mod(a, d);
+            if x == 1 || x == n - 1 { return true; }
+            for _ in 1..s {
+                x = mul_mod(x, x);
+                if x == n - 1 { return true; }
+            }
+            false
+        })
+    }
+
+    /// A lazy iterator over successive primes, created by [`iter_from`].
+    ///
+    /// Each call to `next` advances to the following prime using `next_prime`, so no
+    /// list of primes is ever materialized.
+    #[derive(Debug, Clone)]
+    pub struct PrimeIter {
+        current: u64,
+    }
+
+    impl Iterator for PrimeIter {
+        type Item = u64;
+
+        fn next(&mut self) -> Option<u64> {
+            self.current = next_prime(self.current);
+            Some(self.current)
+        }
+    }
+
+    /// Counts the primes less than or equal to `x`, i.e. the prime-counting function π(x).
+    pub fn prime_pi(x: u64) -> u64 {
+        primes_in_range(2, x).len() as u64
+    }
+
+    /// Returns the `n`th prime, counting from 1, so `nth_prime(1) == 2`.
+    ///
+    /// # Panics
+    /// Panics if `n` is 0.
+    pub fn nth_prime(n: u64) -> u64 {
+        assert!(n >= 1, "Primes are 1-indexed; n must be at least 1.");
+        let mut p = 2;
+        for _ in 1..n {
+            p = next_prime(p);
+        }
+        p
+    }
+
+    /// Returns an iterator over all primes greater than or equal to `start`.
+    ///
+    /// The iterator never terminates on its own, so it must be bounded by the caller,
+    /// e.g. `primes::iter_from(2).take_while(|&p| p < 1000)`.
+    pub fn iter_from(start: u64) -> PrimeIter {
+        PrimeIter { current: start.saturating_sub(1) }
+    }
+
+    /// Computes the autocorrelation of the prime gap sequence for primes in `[start, end]`
+    /// at lags `0..=max_lag`, using `score::autocorrelation`.
+    ///
+    /// The result always has `max_lag + 1` entries. Lag 0 is 1.0 whenever the range
+    /// contains at least one gap; an empty gap sequence yields all zeros.
+    ///
+    /// Empirically, consecutive gaps are weakly anti-correlated at lag 1 and close to
+    /// zero beyond, so values far from zero at small lags usually indicate too short a
+    /// range rather than genuine structure.
+    pub fn gap_autocorrelation(start: u64, end: u64, max_lag: usize) -> Vec<f64> {
+        let gaps: Vec<f64> = primes_in_range(start, end)
+            .windows(2)
+            .map(|w| (w[1] - w[0]) as f64)
+            .collect();
+        if gaps.is_empty() { return vec![0.0; max_lag + 1]; }
+        score::autocorrelation(&gaps, max_lag)
+    }
+
+    /// The number of candidates `random_prime_in` samples before giving up.
+    #[cfg(feature = "rand")]
+    const RANDOM_PRIME_ATTEMPTS: usize = 10_000;
+
+    /// Samples random candidates from `range` and returns the first prime found.
+    ///
+    /// With a seeded RNG (e.g. `StdRng::seed_from_u64`) the result is reproducible,
+    /// which makes this convenient for tests. Returns `None` if the range is empty or
+    /// no prime is hit within a bounded number of attempts.
+    ///
+    /// This is not constant-time: the number of samples drawn, and therefore the
+    /// running time, depends on the RNG output and the density of primes in `range`.
+    #[cfg(feature = "rand")]
+    pub fn random_prime_in(range: Range<u64>, rng: &mut impl rand::RngCore) -> Option<u64> {
+        use rand::Rng;
+        if range.is_empty() { return None; }
+        (0..RANDOM_PRIME_ATTEMPTS)
+            .map(|_| rng.random_range(range.clone()))
+            .find(|&candidate| is_prime(candidate))
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digit_sum() {
+        assert_eq!(digit_sum(255, 16), 30);
+        assert_eq!(digit_sum(12345, 10), 15);
+        assert_eq!(digit_sum(0, 10), 0);
+    }
+
+    #[test]
+    fn test_digital_root() {
+        assert_eq!(digital_root(12345, 10), 6);
+        assert_eq!(digital_root(9, 10), 9);
+        assert_eq!(digital_root(0, 10), 0);
+        assert_eq!(digital_root(5, 2), 1);
+    }
+
+    #[test]
+    fn test_primes_in_range_matches_trial_division() {
+        let ranges = [
+            (0, 0), (0, 1), (0, 2), (2, 2), (0, 100), (13, 13), (14, 14),
+            (90, 200), (100, 90), (1, 2 * SEGMENT_SIZE + 17),
+        ];
+        for (start, end) in ranges {
+            let expected: Vec<u64> = (start..=end).filter(|&n| is_prime(n)).collect();
+            assert_eq!(primes_in_range(start, end), expected, "range [{start}, {end}]");
+        }
+    }
+
+    #[test]
+    fn test_iter_from() {
+        let small: Vec<u64> = iter_from(0).take(4).collect();
+        assert_eq!(small, vec![2, 3, 5, 7]);
+        assert_eq!(iter_from(2).next(), Some(2));
+        assert_eq!(iter_from(3).next(), Some(3));
+        assert_eq!(iter_from(14).next(), Some(17));
+
+        let bounded: Vec<u64> = iter_from(2).take_while(|&p| p < 100).collect();
+        assert_eq!(bounded, primes_in_range(2, 99));
+    }
+
+    #[test]
+    fn test_gap_autocorrelation() {
+        let correlations = gap_autocorrelation(2, 10_000, 5);
+        assert_eq!(correlations.len(), 6);
+        assert!((correlations[0] - 1.0).abs() < 1e-12);
+        assert!(correlations.iter().all(|c| c.abs() <= 1.0 + 1e-12));
+        assert_eq!(gap_autocorrelation(0, 1, 3), vec![0.0; 4]);
+    }
+
+    #[test]
+    fn test_twin_primes() {
+        let below_100 = twin_primes_in_range(0, 100);
+        assert_eq!(below_100.len(), 8);
+        assert_eq!(&below_100[..2], &[(3, 5), (5, 7)]);
+        assert_eq!(below_100.last(), Some(&(71, 73)));
+        assert_eq!(twin_primes_in_range(5, 7), vec![(5, 7)]);
+        assert!(twin_primes_in_range(4, 6).is_empty());
+        assert!(twin_primes_in_range(10, 2).is_empty());
+
+        assert!(is_twin_prime(5));
+        assert!(is_twin_prime(73));
+        assert!(!is_twin_prime(2));
+        assert!(!is_twin_prime(23));
+        assert!(!is_twin_prime(9));
+    }
+
+    #[test]
+    fn test_prime_pi_and_nth_prime() {
+        assert_eq!(prime_pi(0), 0);
+        assert_eq!(prime_pi(2), 1);
+        assert_eq!(prime_pi(10), 4);
+        assert_eq!(prime_pi(1000), 168);
+        assert_eq!(nth_prime(1), 2);
+        assert_eq!(nth_prime(6), 13);
+        assert_eq!(nth_prime(168), 997);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_nth_prime_rejects_zero() {
+        nth_prime(0);
+    }
+
+    #[test]
+    fn test_factorize() {
+        assert!(factorize(0).is_empty());
+        assert!(factorize(1).is_empty());
+        assert_eq!(factorize(97), vec![(97, 1)]);
+        assert_eq!(factorize(360), vec![(2, 3), (3, 2), (5, 1)]);
+        for n in 2..2000 {
+            let product: u64 = factorize(n).iter().map(|&(p, e)| p.pow(e)).product();
+            assert_eq!(product, n);
+            assert!(factorize(n).iter().all(|&(p, _)| is_prime(p)));
+        }
+        assert_eq!(prime_factor_mass(12), 3);
+    }
+
+    #[test]
+    fn test_factor_cache_matches_prime_factor_mass() {
+        let mut cache = FactorCache::new();
+        let mut sieved = FactorCache::with_sieve(1_000);
+        for n in (0..2_000).chain([1_000_003 * 6, u64::MAX]) {
+            let expected = prime_factor_mass(n);
+            assert_eq!(cache.mass(n), expected, "n = {n}");
+            assert_eq!(cache.mass(n), expected, "n = {n} (cached)");
+            assert_eq!(sieved.mass(n), expected, "n = {n} (sieved)");
+        }
+    }
+
+    #[test]
+    fn test_totient_and_mobius() {
+        assert_eq!(euler_totient(0), 0);
+        assert_eq!(euler_totient(1), 1);
+        assert_eq!(euler_totient(12), 4);
+        assert_eq!(euler_totient(97), 96);
+        assert_eq!(euler_totient(1 << 40), 1 << 39);
+        let gcd = |mut a: u64, mut b: u64| {
+            while b != 0 { (a, b) = (b, a % b); }
+            a
+        };
+        for n in 1..200u64 {
+            let coprime = (1..=n).filter(|&k| gcd(n, k) == 1).count() as u64;
+            assert_eq!(euler_totient(n), coprime, "n = {n}");
+        }
+
+        assert_eq!(mobius(0), 0);
+        assert_eq!(mobius(1), 1);
+        assert_eq!(mobius(30), -1);
+        assert_eq!(mobius(12), 0);
+        assert_eq!(mobius(6), 1);
+        assert_eq!(mobius(7), -1);
+    }
+
+    #[test]
+    fn test_maximal_gaps_in_range() {
+        assert_eq!(
+            maximal_gaps_in_range(0, 100),
+            vec![(2, 3, 1), (3, 5, 2), (7, 11, 4), (23, 29, 6), (89, 97, 8)]
+        );
+        // The next classical records are 14 after 113 and 18 after 523.
+        let records = maximal_gaps_in_range(2, 600);
+        assert_eq!(records[5..], [(113, 127, 14), (523, 541, 18)]);
+        // Records restart from the first gap of a later window.
+        assert_eq!(maximal_gaps_in_range(30, 40), vec![(31, 37, 6)]);
+        assert!(maximal_gaps_in_range(24, 28).is_empty());
+    }
+
+    #[test]
+    fn test_average_and_expected_gap() {
+        // Primes in [2, 30]: 2, 3, 5, 7, 11, 13, 17, 19, 23, 29 -> 9 gaps spanning 27.
+        assert_eq!(average_gap(2, 30), 3.0);
+        assert_eq!(average_gap(24, 28), 0.0);
+        assert_eq!(average_gap(29, 30), 0.0);
+        assert_eq!(average_gap(30, 2), 0.0);
+
+        let expected: Vec<f64> = [3, 10, 100, 1_000, 1 << 40].iter().map(|&p| expected_gap(p)).collect();
+        assert!(expected.windows(2).all(|w| w[0] < w[1]));
+        assert!((expected_gap(23) - 23f64.ln()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_almost_primes() {
+        assert!(is_semiprime(15));
+        assert!(is_semiprime(9));
+        assert!(!is_semiprime(12));
+        assert!(!is_semiprime(7));
+        assert!(!is_semiprime(1));
+        assert!(is_k_almost_prime(8, 3));
+        assert!(is_k_almost_prime(12, 3));
+        assert!(!is_k_almost_prime(8, 2));
+        assert!(is_k_almost_prime(1, 0));
+        assert!((2..100).all(|n| is_k_almost_prime(n, 1) == is_prime(n)));
+    }
+
+    #[test]
+    fn test_primes_in_range_narrow_windows() {
+        // Narrow windows take the Miller-Rabin path; it agrees with trial division.
+        let narrow = primes_in_range(1_000_000, 1_000_500);
+        let trial: Vec<u64> = (1_000_000..=1_000_500).filter(|&n| is_prime(n)).collect();
+        assert_eq!(narrow, trial);
+        assert!((2..2000).all(|n| miller_rabin(n) == is_prime(n)));
+
+        // Far windows need neither a √end-sized sieve nor overflow-prone multiples.
+        // The largest primes below 2^64 are 2^64 - 95, 2^64 - 83 and 2^64 - 59.
+        assert_eq!(primes_in_range(u64::MAX - 100, u64::MAX), vec![u64::MAX - 94, u64::MAX - 82, u64::MAX - 58]);
+        assert!(primes_in_range(u64::MAX, u64::MAX).is_empty());
+        let near_quintillion = primes_in_range(1_000_000_000_000_000_000, 1_000_000_000_000_000_010);
+        assert_eq!(near_quintillion, vec![1_000_000_000_000_000_003, 1_000_000_000_000_000_009]);
+        // A Carmichael number and a strong pseudoprime to base 2 are rejected.
+        assert!(!miller_rabin(561));
+        assert!(!miller_rabin(3_215_031_751));
+    }
+
+    #[test]
+    fn test_primorial() {
+        assert_eq!(primorial(0), 1);
+        assert_eq!(primorial(1), 2);
+        assert_eq!(primorial(3), 30);
+        assert_eq!(primorial(MAX_PRIMORIAL_INDEX), 614_889_782_588_491_410);
+        assert!(primorial(MAX_PRIMORIAL_INDEX).checked_mul(53).is_none());
+        for k in 0..=MAX_PRIMORIAL_INDEX {
+            assert_eq!(primorial(k), iter_from(2).take(k as usize).product::<u64>());
         }
-        count
-    }
\ No newline at end of file
+    }
+
+    #[test]
+    fn test_primorial_clamps_past_cap() {
+        assert_eq!(primorial(16), primorial(15));
+        assert_eq!(primorial(u64::MAX), primorial(MAX_PRIMORIAL_INDEX));
+    }
+
+    #[test]
+    fn test_sigma() {
+        assert_eq!(sigma(0), 0);
+        assert_eq!(sigma(1), 1);
+        assert_eq!(sigma(6), 12);
+        assert_eq!(sigma(12), 28);
+        assert_eq!(sigma(13), 14);
+        assert_eq!(sigma(1 << 10), (1 << 11) - 1);
+        for n in 1..200u64 {
+            let brute: u64 = (1..=n).filter(|d| n.is_multiple_of(*d)).sum();
+            assert_eq!(sigma(n), brute, "n = {n}");
+        }
+    }
+
+    #[test]
+    fn test_omega() {
+        assert_eq!(omega(1), 0);
+        assert_eq!(omega(12), 2);
+        assert_eq!(omega(30), 3);
+        assert_eq!(omega(64), 1);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn test_random_prime_in_is_reproducible() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let first = random_prime_in(1_000..2_000, &mut StdRng::seed_from_u64(7));
+        let second = random_prime_in(1_000..2_000, &mut StdRng::seed_from_u64(7));
+        assert_eq!(first, second);
+        let p = first.unwrap();
+        assert!((1_000..2_000).contains(&p) && is_prime(p));
+
+        assert_eq!(random_prime_in(24..29, &mut StdRng::seed_from_u64(7)), None);
+        assert_eq!(random_prime_in(10..10, &mut StdRng::seed_from_u64(7)), None);
+    }
+
+    #[test]
+    fn test_palindromic_primes_and_emirps() {
+        assert!(is_palindromic_prime(131));
+        assert!(is_palindromic_prime(11));
+        assert!(!is_palindromic_prime(121)); // 11 * 11
+        assert!(is_emirp(13));
+        assert!(!is_emirp(11));
+        assert!(!is_emirp(19)); // 91 = 7 * 13
+        assert!(!is_emirp(u64::MAX));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_digit_sum_rejects_radix_below_two() {
+        digit_sum(10, 1);
+    }
+}
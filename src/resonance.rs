@@ -3,34 +3,85 @@
 
 use crate::core::{MomaRing, OriginStrategy};
 use crate::primes;
+use crate::score;
 use std::marker::PhantomData;
 
 /// A function pointer type that defines a property of a prime number.
 /// This is used as the target for resonance checks.
 pub type PrimePropertyFn = fn(u64) -> u64;
 
+/// A function pointer type that defines a real-valued property of a prime number,
+/// such as `ln(p)`.
+pub type FloatPropertyFn = fn(u64) -> f64;
+
+/// The property a `ResonanceFinder` compares signatures against.
+enum Property<F> {
+    /// An integer property; resonance means exact divisibility.
+    Integer(F),
+    /// A real-valued property; resonance means closeness to an integer multiple.
+    Float { f: FloatPropertyFn, tolerance: f64 },
+}
+
+/// The alignment between a signature and a property value that counts as resonance.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ResonanceCondition {
+    /// The property value is positive and divides the signature.
+    Divides,
+    /// The signature equals the property value.
+    Equals,
+    /// The signature is within the given distance of the property value (inclusive).
+    NearWithin(u64),
+    /// The signature and the property value share a factor greater than 1.
+    ShareFactor,
+}
+
+impl ResonanceCondition {
+    /// Checks whether `signature` and `property_value` satisfy this condition.
+    pub fn holds(&self, signature: u64, property_value: u64) -> bool {
+        match *self {
+            ResonanceCondition::Divides => property_value > 0 && signature.is_multiple_of(property_value),
+            ResonanceCondition::Equals => signature == property_value,
+            ResonanceCondition::NearWithin(tolerance) => signature.abs_diff(property_value) <= tolerance,
+            ResonanceCondition::ShareFactor => gcd(signature, property_value) > 1,
+        }
+    }
+}
+
+/// Computes the greatest common divisor with Euclid's algorithm; `gcd(0, 0) == 0`.
+fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a
+}
+
 /// An analyzer that finds primes where the MOMA signature "resonates" with
 /// another property of the prime.
 ///
-/// Resonance occurs when `ring.signature(p) % property_fn(p) == 0`.
-pub struct ResonanceFinder<S: OriginStrategy> {
+/// Resonance occurs when `ring.signature(p) % property_fn(p) == 0`, or, for
+/// real-valued properties, when the signature is near an integer multiple of the
+/// property (see [`ResonanceFinder::with_float_property`]).
+///
+/// The integer property is generic over `F`, so besides plain functions it accepts
+/// closures that capture state, such as a precomputed table of factor masses.
+pub struct ResonanceFinder<S: OriginStrategy, F: Fn(u64) -> u64 = PrimePropertyFn> {
     ring: MomaRing<S>,
-    property_fn: PrimePropertyFn,
+    property: Property<F>,
     _strategy: PhantomData<S>,
 }
 
-impl<S: OriginStrategy> ResonanceFinder<S> {
+impl<S: OriginStrategy, F: Fn(u64) -> u64> ResonanceFinder<S, F> {
     /// Creates a new `ResonanceFinder`.
     ///
     /// # Arguments
     /// * `modulus` - The modulus for the internal `MomaRing`.
     /// * `strategy` - The `OriginStrategy` to use for generating signatures.
     /// * `property_fn` - A function that defines the property to check for resonance against.
-    ///   For example, `primes::prime_factor_mass` could be used.
-    pub fn new(modulus: u64, strategy: S, property_fn: PrimePropertyFn) -> Self {
+    ///   For example, `primes::prime_factor_mass` could be used, or a closure.
+    pub fn new(modulus: u64, strategy: S, property_fn: F) -> Self {
         Self {
             ring: MomaRing::new(modulus, strategy),
-            property_fn,
+            property: Property::Integer(property_fn),
             _strategy: PhantomData,
         }
     }
@@ -41,14 +92,43 @@ impl<S: OriginStrategy> ResonanceFinder<S> {
     /// `Some(signature)` if resonance occurs, otherwise `None`.
     pub fn check_prime(&self, p: u64) -> Option<u64> {
         let signature = self.ring.signature(p);
-        let property_value = (self.property_fn)(p);
 
-        // Avoid division by zero and check for resonance.
-        if property_value > 0 && signature % property_value == 0 {
-            Some(signature)
-        } else {
-            None
-        }
+        let resonates = match &self.property {
+            Property::Integer(property_fn) => ResonanceCondition::Divides.holds(signature, property_fn(p)),
+            &Property::Float { f, tolerance } => {
+                let property_value = f(p);
+                property_value.is_finite()
+                    && property_value > 0.0
+                    && (signature as f64 / property_value).fract() < tolerance
+            }
+        };
+
+        if resonates { Some(signature) } else { None }
+    }
+
+    /// Checks a single prime for resonance under an explicit `ResonanceCondition`.
+    ///
+    /// `check_prime` is this method with `ResonanceCondition::Divides` for integer
+    /// properties. For a real-valued property (see `with_float_property`) the property
+    /// value is rounded to the nearest integer and the tolerance is not used; a value
+    /// that is not a non-negative, finite number never resonates.
+    ///
+    /// # Returns
+    /// `Some(signature)` if resonance occurs, otherwise `None`.
+    pub fn check_prime_with(&self, p: u64, condition: ResonanceCondition) -> Option<u64> {
+        let signature = self.ring.signature(p);
+        let property_value = match &self.property {
+            Property::Integer(property_fn) => property_fn(p),
+            Property::Float { f, .. } => {
+                let value = f(p).round();
+                if !value.is_finite() || value < 0.0 {
+                    return None;
+                }
+                value as u64
+            }
+        };
+
+        if condition.holds(signature, property_value) { Some(signature) } else { None }
     }
 
     /// Finds all primes within a given range that exhibit resonance.
@@ -60,15 +140,305 @@ impl<S: OriginStrategy> ResonanceFinder<S> {
     /// # Returns
     /// A `Vec` of tuples `(prime, signature)` for each resonance event found.
     pub fn find_in_range(&self, start_range: u64, end_range: u64) -> Vec<(u64, u64)> {
-        let mut p = primes::next_prime(start_range.saturating_sub(1));
-        let mut resonances = Vec::new();
+        self.resonances_iter(start_range, end_range).collect()
+    }
 
-        while p < end_range {
-            if let Some(signature) = self.check_prime(p) {
-                resonances.push((p, signature));
-            }
-            p = primes::next_prime(p);
+    /// Lazily yields the resonance events for primes in `[start_range, end_range)`.
+    ///
+    /// Primes are generated on demand with `next_prime`, so callers can stop early
+    /// (e.g. with `take(10)`) or stream events without collecting the whole range.
+    /// The sequence is the same as that returned by `find_in_range`.
+    pub fn resonances_iter(&self, start_range: u64, end_range: u64) -> impl Iterator<Item = (u64, u64)> + '_ {
+        let first = primes::next_prime(start_range.saturating_sub(1));
+        std::iter::successors(Some(first), |&p| Some(primes::next_prime(p)))
+            .take_while(move |&p| p < end_range)
+            .filter_map(|p| self.check_prime(p).map(|signature| (p, signature)))
+    }
+}
+
+impl<S: OriginStrategy> ResonanceFinder<S> {
+    /// Creates a new `ResonanceFinder` that resonates against a real-valued property.
+    ///
+    /// A prime `p` resonates when its signature lies just above an integer multiple of
+    /// `f(p)`, i.e. when `(signature as f64 / f(p)).fract() < tolerance`. The tolerance
+    /// is therefore measured in units of `f(p)`: a tolerance of `0.05` accepts signatures
+    /// up to 5% of `f(p)` past a multiple. Primes where `f(p)` is not a positive, finite
+    /// number never resonate.
+    ///
+    /// # Arguments
+    /// * `modulus` - The modulus for the internal `MomaRing`.
+    /// * `strategy` - The `OriginStrategy` to use for generating signatures.
+    /// * `f` - A function that defines the real-valued property, e.g. `|p| (p as f64).ln()`.
+    /// * `tolerance` - The maximum fractional part of `signature / f(p)` that counts as resonance.
+    pub fn with_float_property(modulus: u64, strategy: S, f: FloatPropertyFn, tolerance: f64) -> Self {
+        Self {
+            ring: MomaRing::new(modulus, strategy),
+            property: Property::Float { f, tolerance },
+            _strategy: PhantomData,
         }
-        resonances
     }
-}
\ No newline at end of file
+}
+
+/// A detector that finds resonant structure in a real-valued series, such as a
+/// history of signatures or origin-shift magnitudes.
+pub trait ResonanceDetector {
+    /// Analyzes `series` and returns the detected resonances. The meaning of the
+    /// returned values (frequencies, periods, ...) is defined by each implementor.
+    fn detect(&self, series: &[f64]) -> Vec<f64>;
+}
+
+/// A time-domain resonance detector based on the autocorrelation of the series.
+///
+/// A periodic series correlates strongly with itself when shifted by a whole period,
+/// so peaks of the autocorrelation reveal the periods present in the series.
+#[derive(Debug, Clone, Copy)]
+pub struct AutocorrelationDetector {
+    /// The largest lag (period, in samples) to consider.
+    pub max_lag: usize,
+    /// The minimum autocorrelation for a peak to count as resonant.
+    pub threshold: f64,
+}
+
+impl AutocorrelationDetector {
+    /// Creates a new `AutocorrelationDetector` searching lags `1..=max_lag`.
+    pub fn new(max_lag: usize, threshold: f64) -> Self {
+        Self { max_lag, threshold }
+    }
+}
+
+impl ResonanceDetector for AutocorrelationDetector {
+    /// Returns the lags, in samples, at which the autocorrelation has a local peak
+    /// above the threshold, strongest first. The first entry is therefore the
+    /// dominant period; its multiples usually follow as weaker peaks.
+    fn detect(&self, series: &[f64]) -> Vec<f64> {
+        let correlations = score::autocorrelation(series, self.max_lag + 1);
+        if correlations.len() < 3 {
+            return Vec::new();
+        }
+        let mut peaks: Vec<(usize, f64)> = (1..=self.max_lag)
+            .filter(|&lag| {
+                let r = correlations[lag];
+                r > self.threshold && r > correlations[lag - 1] && r >= correlations[lag + 1]
+            })
+            .map(|lag| (lag, correlations[lag]))
+            .collect();
+        peaks.sort_by(|a, b| b.1.total_cmp(&a.1));
+        peaks.into_iter().map(|(lag, _)| lag as f64).collect()
+    }
+}
+
+/// A frequency-domain resonance detector based on the FFT.
+///
+/// The series is assumed to be sampled at unit rate (one sample per step), so the
+/// reported frequencies are in cycles per sample and range over `(0, 0.5]`. Divide
+/// by the sampling interval to convert to physical units.
+#[cfg(feature = "rustfft")]
+#[derive(Debug, Clone, Copy)]
+pub struct SpectralDetector {
+    /// The minimum spectral magnitude for a frequency to count as resonant.
+    pub threshold: f64,
+}
+
+#[cfg(feature = "rustfft")]
+impl SpectralDetector {
+    /// Creates a new `SpectralDetector` with the given magnitude threshold.
+    pub fn new(threshold: f64) -> Self {
+        Self { threshold }
+    }
+
+    /// Computes the one-sided magnitude spectrum of `series` as `(frequency, magnitude)`
+    /// pairs, excluding the zero-frequency (DC) bin.
+    ///
+    /// The series is zero-padded to the next power of two, so a series of length `n`
+    /// padded to `m` has bins at frequencies `k / m`. Padding interpolates the spectrum
+    /// rather than adding resolution. Magnitudes are normalized as `2|X_k| / n` using
+    /// the unpadded length, so a pure sinusoid of amplitude `A` whose frequency falls
+    /// exactly on a bin yields a magnitude of (approximately, when padded) `A` there.
+    ///
+    /// The magnitudes can be passed to `score::score_signal_to_noise` to judge how
+    /// strongly the dominant peak stands out.
+    pub fn spectrum(&self, series: &[f64]) -> Vec<(f64, f64)> {
+        use rustfft::FftPlanner;
+        use rustfft::num_complex::Complex;
+
+        let n = series.len();
+        if n < 2 {
+            return Vec::new();
+        }
+        let padded_len = n.next_power_of_two();
+        let mut buffer: Vec<Complex<f64>> = series.iter().map(|&x| Complex::new(x, 0.0)).collect();
+        buffer.resize(padded_len, Complex::new(0.0, 0.0));
+        FftPlanner::new().plan_fft_forward(padded_len).process(&mut buffer);
+
+        (1..=padded_len / 2)
+            .map(|k| (k as f64 / padded_len as f64, 2.0 * buffer[k].norm() / n as f64))
+            .collect()
+    }
+}
+
+#[cfg(feature = "rustfft")]
+impl ResonanceDetector for SpectralDetector {
+    /// Returns the frequencies, in cycles per sample, whose spectral magnitude exceeds
+    /// the threshold, in ascending order.
+    fn detect(&self, series: &[f64]) -> Vec<f64> {
+        self.spectrum(series)
+            .into_iter()
+            .filter(|&(_, magnitude)| magnitude > self.threshold)
+            .map(|(frequency, _)| frequency)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{Fixed, PrimeGap};
+
+    fn ln(p: u64) -> f64 {
+        (p as f64).ln()
+    }
+
+    #[test]
+    fn test_float_property_resonance() {
+        // With a zero origin, signature(p) = (p + prev_prime(p)) mod 100.
+        let finder = ResonanceFinder::with_float_property(100, Fixed(0), ln, 0.1);
+        // 78 / ln 41 ≈ 21.004, 0 / ln 53 = 0, 62 / ln 83 ≈ 14.031, 72 / ln 89 ≈ 16.041.
+        let expected = vec![(41, 78), (53, 0), (83, 62), (89, 72)];
+        assert_eq!(finder.find_in_range(3, 100), expected);
+        // 12 / ln 7 ≈ 6.167 misses a tolerance of 0.1 but meets one of 0.2.
+        assert_eq!(finder.check_prime(7), None);
+        let looser = ResonanceFinder::with_float_property(100, Fixed(0), ln, 0.2);
+        assert_eq!(looser.check_prime(7), Some(12));
+    }
+
+    #[test]
+    fn test_resonances_iter_matches_find_in_range() {
+        let finder = ResonanceFinder::new(100, PrimeGap, primes::prime_factor_mass);
+        let collected = finder.find_in_range(3, 2_000);
+        let streamed: Vec<(u64, u64)> = finder.resonances_iter(3, 2_000).collect();
+        assert!(!collected.is_empty());
+        assert_eq!(streamed, collected);
+
+        let first_three: Vec<(u64, u64)> = finder.resonances_iter(3, u64::MAX).take(3).collect();
+        assert_eq!(first_three, collected[..3]);
+        assert_eq!(finder.resonances_iter(100, 50).next(), None);
+    }
+
+    #[test]
+    fn test_resonance_conditions() {
+        // Fixed(0) mod 100: the signature of 7 is 7 + 5 = 12.
+        let constant = |property_fn: PrimePropertyFn| ResonanceFinder::new(100, Fixed(0), property_fn);
+
+        let four = constant(|_| 4);
+        assert_eq!(four.check_prime(7), Some(12));
+        assert_eq!(four.check_prime_with(7, ResonanceCondition::Divides), Some(12));
+        assert_eq!(four.check_prime_with(7, ResonanceCondition::Equals), None);
+        assert_eq!(four.check_prime_with(7, ResonanceCondition::ShareFactor), Some(12));
+
+        let twelve = constant(|_| 12);
+        assert_eq!(twelve.check_prime_with(7, ResonanceCondition::Equals), Some(12));
+
+        let five = constant(|_| 5);
+        assert_eq!(five.check_prime_with(7, ResonanceCondition::Divides), None);
+        assert_eq!(five.check_prime_with(7, ResonanceCondition::ShareFactor), None);
+        assert_eq!(five.check_prime_with(7, ResonanceCondition::NearWithin(6)), None);
+        assert_eq!(five.check_prime_with(7, ResonanceCondition::NearWithin(7)), Some(12));
+
+        let nine = constant(|_| 9);
+        assert_eq!(nine.check_prime_with(7, ResonanceCondition::Divides), None);
+        assert_eq!(nine.check_prime_with(7, ResonanceCondition::ShareFactor), Some(12));
+
+        assert_eq!(gcd(0, 0), 0);
+        assert!(!ResonanceCondition::Divides.holds(12, 0));
+    }
+
+    #[test]
+    fn test_capturing_closure_property() {
+        // A precomputed mass table captured by the property closure.
+        let masses: std::collections::HashMap<u64, u64> = primes::primes_in_range(2, 500)
+            .into_iter()
+            .map(|p| (p, primes::prime_factor_mass(p + 1)))
+            .collect();
+        let cached = ResonanceFinder::new(100, PrimeGap, |p| masses[&p]);
+        let direct = ResonanceFinder::new(100, PrimeGap, |p| primes::prime_factor_mass(p + 1));
+        let found = cached.find_in_range(3, 500);
+        assert!(!found.is_empty());
+        assert_eq!(found, direct.find_in_range(3, 500));
+
+        // Plain function pointers keep working through the default type parameter.
+        let pointer: ResonanceFinder<PrimeGap> = ResonanceFinder::new(100, PrimeGap, primes::prime_factor_mass);
+        assert_eq!(pointer.find_in_range(3, 500).len(), pointer.resonances_iter(3, 500).count());
+    }
+
+    #[test]
+    fn test_float_property_accepts_zero_signature() {
+        // ln(2) > 0 and the signature of 2 is 0, which is an exact multiple.
+        let finder = ResonanceFinder::with_float_property(10, Fixed(0), ln, 0.01);
+        assert_eq!(finder.check_prime(2), Some(0));
+    }
+
+    #[test]
+    fn test_float_property_rejects_non_positive_values() {
+        let zero = ResonanceFinder::with_float_property(10, Fixed(0), |_| 0.0, 0.5);
+        assert_eq!(zero.check_prime(13), None);
+        let negative = ResonanceFinder::with_float_property(10, Fixed(0), |_| -1.0, 0.5);
+        assert_eq!(negative.check_prime(13), None);
+        let nan = ResonanceFinder::with_float_property(10, Fixed(0), |_| f64::NAN, 0.5);
+        assert_eq!(nan.check_prime(2), None);
+    }
+
+    #[test]
+    fn test_autocorrelation_detector_finds_period() {
+        // 128 samples of a sine with a period of 16 samples.
+        let series: Vec<f64> = (0..128)
+            .map(|t| (2.0 * std::f64::consts::PI * t as f64 / 16.0).sin())
+            .collect();
+        let detector = AutocorrelationDetector::new(40, 0.3);
+        let periods = detector.detect(&series);
+        assert_eq!(periods.first(), Some(&16.0));
+        assert!(periods.contains(&32.0));
+
+        assert!(detector.detect(&[]).is_empty());
+        assert!(detector.detect(&[1.0; 50]).is_empty());
+    }
+
+    #[cfg(feature = "rustfft")]
+    #[test]
+    fn test_spectral_detector_recovers_sinusoid() {
+        // 64 samples of a sine completing 8 cycles: frequency 8/64 = 0.125.
+        let n = 64;
+        let series: Vec<f64> = (0..n)
+            .map(|t| 3.0 * (2.0 * std::f64::consts::PI * 8.0 * t as f64 / n as f64).sin())
+            .collect();
+        let detector = SpectralDetector::new(1.0);
+        assert_eq!(detector.detect(&series), vec![0.125]);
+
+        let peak = detector
+            .spectrum(&series)
+            .into_iter()
+            .max_by(|a, b| a.1.total_cmp(&b.1))
+            .unwrap();
+        assert!((peak.1 - 3.0).abs() < 1e-9);
+    }
+
+    #[cfg(feature = "rustfft")]
+    #[test]
+    fn test_spectral_detector_pads_non_power_of_two() {
+        // 100 samples at frequency 0.125, padded to 128 samples.
+        let series: Vec<f64> = (0..100)
+            .map(|t| 2.0 * (2.0 * std::f64::consts::PI * 0.125 * t as f64).sin())
+            .collect();
+        let detector = SpectralDetector::new(1.0);
+        let spectrum = detector.spectrum(&series);
+        assert_eq!(spectrum.len(), 64);
+        assert_eq!(spectrum[0].0, 1.0 / 128.0);
+
+        let peak = spectrum.iter().copied().max_by(|a, b| a.1.total_cmp(&b.1)).unwrap();
+        assert_eq!(peak.0, 0.125);
+        assert!((peak.1 - 2.0).abs() < 0.1);
+        assert!(detector.detect(&series).contains(&0.125));
+
+        let magnitudes: Vec<f64> = spectrum.iter().map(|&(_, magnitude)| magnitude).collect();
+        assert!(crate::score::score_signal_to_noise(&magnitudes) > 5.0);
+    }
+}
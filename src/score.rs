@@ -1,17 +1,274 @@
 //! Scores resonance strength from a spectrum or autocorrelation series
+//!
+//! The scoring functions ignore NaN entries and return 0.0 when no usable data
+//! remains or the score would be undefined (zero mean or zero variance), so an
+//! empty series never yields NaN or infinity.
 
+use std::collections::HashMap;
+
+/// Returns the entries of `data` that are not NaN.
+fn non_nan(data: &[f64]) -> Vec<f64> {
+    data.iter().copied().filter(|x| !x.is_nan()).collect()
+}
+
+/// Scores peak prominence as the ratio of the maximum to the mean.
+///
+/// Returns 0.0 for empty (or all-NaN) input and when the mean is zero.
 pub fn score_signal_to_noise(data: &[f64]) -> f64 {
+    let data = non_nan(data);
     if data.is_empty() { return 0.0; }
     let max = data.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
     let mean = data.iter().sum::<f64>() / data.len() as f64;
+    if mean == 0.0 { return 0.0; }
     max / mean
 }
 
 /// Scores peak sharpness using normalized kurtosis
+///
+/// Returns 0.0 for empty (or all-NaN) input and when the variance is zero,
+/// which includes any single-element series.
 pub fn score_kurtosis(data: &[f64]) -> f64 {
+    let data = non_nan(data);
+    if data.is_empty() { return 0.0; }
     let mean = data.iter().sum::<f64>() / data.len() as f64;
     let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / data.len() as f64;
     let fourth_moment = data.iter().map(|x| (x - mean).powi(4)).sum::<f64>() / data.len() as f64;
     if variance == 0.0 { return 0.0; }
     fourth_moment / variance.powi(2)
-}
\ No newline at end of file
+}
+
+/// Scores peak asymmetry using the standardized third moment (`m3 / m2^1.5`).
+///
+/// Positive values indicate a longer right tail, negative values a longer left tail.
+/// Returns 0.0 for empty input or zero variance.
+pub fn score_skewness(data: &[f64]) -> f64 {
+    let data = non_nan(data);
+    if data.is_empty() { return 0.0; }
+    let n = data.len() as f64;
+    let mean = data.iter().sum::<f64>() / n;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / n;
+    let third_moment = data.iter().map(|x| (x - mean).powi(3)).sum::<f64>() / n;
+    if variance == 0.0 { return 0.0; }
+    third_moment / variance.powf(1.5)
+}
+
+/// Scores peakiness as the crest factor: the largest absolute value divided by the
+/// root-mean-square. A pure sinusoid scores √2; isolated spikes score much higher.
+/// Returns 0.0 for empty or all-zero input.
+pub fn score_crest_factor(data: &[f64]) -> f64 {
+    let data = non_nan(data);
+    if data.is_empty() { return 0.0; }
+    let peak = data.iter().fold(0.0, |acc: f64, x| acc.max(x.abs()));
+    let rms = (data.iter().map(|x| x * x).sum::<f64>() / data.len() as f64).sqrt();
+    if rms == 0.0 { return 0.0; }
+    peak / rms
+}
+
+/// Computes Pearson's chi-square statistic of residues against a uniform
+/// distribution over `[0, modulus)`: `Σ (O_k - E)² / E` with `E = n / modulus`.
+///
+/// Under the uniform hypothesis the statistic follows a chi-square distribution with
+/// `modulus - 1` degrees of freedom, so values far above `modulus - 1` indicate biased
+/// residues. Perfectly even counts score 0.0. Returns 0.0 for empty input.
+///
+/// # Panics
+/// Panics if `modulus` is 0 or any residue is not below `modulus`.
+pub fn chi_square_uniform(observed: &[u64], modulus: u64) -> f64 {
+    assert!(modulus > 0, "Modulus must be positive.");
+    if observed.is_empty() { return 0.0; }
+    // Count only the residues that occur, so memory scales with the input rather
+    // than the modulus (which may be as large as a random u32 prime).
+    let mut counts: HashMap<u64, u64> = HashMap::new();
+    for &residue in observed {
+        assert!(residue < modulus, "Residue {residue} is not below the modulus {modulus}.");
+        *counts.entry(residue).or_insert(0) += 1;
+    }
+    let expected = observed.len() as f64 / modulus as f64;
+    let observed_terms: f64 = counts
+        .values()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum();
+    // Each empty bin contributes (0 - E)² / E = E.
+    let empty_bins = (modulus - counts.len() as u64) as f64;
+    observed_terms + empty_bins * expected
+}
+
+/// Computes the normalized autocorrelation of a series at lags `0..=max_lag`.
+///
+/// Each value is the lagged autocovariance divided by the variance, so lag 0 is
+/// always 1.0. Lags at or beyond the series length have no overlapping pairs and
+/// are reported as 0.0. Returns an empty vector for empty input.
+///
+/// Unlike the scores, NaN entries are not skipped, since removing them would shift
+/// the lags; a NaN anywhere in the series propagates to every lag.
+pub fn autocorrelation(data: &[f64], max_lag: usize) -> Vec<f64> {
+    if data.is_empty() { return Vec::new(); }
+    let n = data.len();
+    let mean = data.iter().sum::<f64>() / n as f64;
+    let variance = data.iter().map(|x| (x - mean).powi(2)).sum::<f64>();
+    if variance == 0.0 { return vec![1.0; max_lag + 1]; }
+
+    (0..=max_lag)
+        .map(|lag| {
+            if lag >= n { return 0.0; }
+            let covariance: f64 = (0..n - lag)
+                .map(|t| (data[t] - mean) * (data[t + lag] - mean))
+                .sum();
+            covariance / variance
+        })
+        .collect()
+}
+
+/// Finds the local maxima of a series whose prominence exceeds `min_prominence`.
+///
+/// A peak's prominence is how far it rises above the higher of its two bases, where
+/// each base is the lowest point between the peak and the nearest strictly higher
+/// point on that side (or the end of the series). A large peak on a rising slope is
+/// therefore measured against the valley beside it, not the series minimum.
+///
+/// The endpoints are never peaks, since a maximum needs a lower neighbour on both
+/// sides. A flat run higher than both neighbours is a single peak, reported at its
+/// middle index (rounding down). NaN entries are never peaks or the neighbours of
+/// peaks, and are ignored when measuring prominence.
+///
+/// # Returns
+/// The indices of the qualifying peaks in increasing order.
+pub fn find_peaks(data: &[f64], min_prominence: f64) -> Vec<usize> {
+    let n = data.len();
+    let mut peaks = Vec::new();
+    let mut i = 1;
+    while i + 1 < n {
+        if data[i] > data[i - 1] {
+            // Extend over a plateau of equal values.
+            let mut end = i;
+            while end + 1 < n && data[end + 1] == data[i] {
+                end += 1;
+            }
+            if end + 1 < n && data[end + 1] < data[i] {
+                let height = data[i];
+                // Walk outwards until a strictly higher point; NaN never stops the walk.
+                let not_higher = |x: &&f64| **x <= height || x.is_nan();
+                let left_base = data[..i]
+                    .iter()
+                    .rev()
+                    .take_while(not_higher)
+                    .fold(f64::INFINITY, |min, &x| min.min(x));
+                let right_base = data[end + 1..]
+                    .iter()
+                    .take_while(not_higher)
+                    .fold(f64::INFINITY, |min, &x| min.min(x));
+                if height - left_base.max(right_base) > min_prominence {
+                    peaks.push((i + end) / 2);
+                }
+            }
+            i = end + 1;
+        } else {
+            i += 1;
+        }
+    }
+    peaks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chi_square_uniform() {
+        let uniform: Vec<u64> = (0..60).map(|i| i % 6).collect();
+        assert!(chi_square_uniform(&uniform, 6).abs() < 1e-12);
+
+        // 12 residues all in one of 4 bins: E = 3, so 81/3 + 3 * 9/3 = 36.
+        assert!((chi_square_uniform(&[2; 12], 4) - 36.0).abs() < 1e-12);
+        assert_eq!(chi_square_uniform(&[], 4), 0.0);
+
+        // A KDF-sized modulus needs no per-bin storage. With n distinct residues,
+        // E = n / m and the statistic is n(1 - E)²/E + (m - n)E = m - n.
+        let modulus = 4_294_967_311; // next_prime(u32::MAX)
+        let residues = [0, 17, 123_456_789, modulus - 1];
+        let statistic = chi_square_uniform(&residues, modulus);
+        assert!((statistic - (modulus - 4) as f64).abs() / statistic < 1e-9);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_chi_square_uniform_rejects_out_of_range_residue() {
+        chi_square_uniform(&[0, 1, 5], 5);
+    }
+
+    #[test]
+    fn test_autocorrelation() {
+        // A period-4 signal correlates fully with itself shifted by 4.
+        let periodic: Vec<f64> = (0..40).map(|t| [0.0, 1.0, 0.0, -1.0][t % 4]).collect();
+        let correlations = autocorrelation(&periodic, 6);
+        assert_eq!(correlations.len(), 7);
+        assert_eq!(correlations[0], 1.0);
+        let peak = (1..=6).max_by(|&a, &b| correlations[a].total_cmp(&correlations[b])).unwrap();
+        assert_eq!(peak, 4);
+        assert!(correlations[2] < 0.0);
+
+        assert_eq!(autocorrelation(&[3.0; 5], 3), vec![1.0; 4]);
+        assert!(autocorrelation(&[], 3).is_empty());
+        assert_eq!(autocorrelation(&[1.0, 2.0], 3)[2..], [0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_find_peaks() {
+        let two_peaks = [0.0, 3.0, 1.0, 1.5, 1.0, 5.0, 0.0];
+        // The bump at index 3 only rises 0.5 above its higher base.
+        assert_eq!(find_peaks(&two_peaks, 1.0), vec![1, 5]);
+        assert_eq!(find_peaks(&two_peaks, 0.1), vec![1, 3, 5]);
+        // Index 1 has prominence 3 - 1 = 2, not 3, because its right base is 1.
+        assert_eq!(find_peaks(&two_peaks, 2.0), vec![5]);
+
+        // Plateaus report their middle; endpoints are never peaks.
+        assert_eq!(find_peaks(&[0.0, 2.0, 2.0, 2.0, 0.0], 1.0), vec![2]);
+        assert_eq!(find_peaks(&[0.0, 2.0, 2.0, 0.0], 1.0), vec![1]);
+        assert!(find_peaks(&[5.0, 1.0, 0.0, 1.0, 5.0], 0.0).is_empty());
+        assert!(find_peaks(&[0.0, 2.0, 2.0, 3.0], 0.0).is_empty());
+        assert!(find_peaks(&[], 0.0).is_empty());
+
+        // NaN is skipped when finding the bases, but cannot border a peak.
+        let gappy = [0.0, f64::NAN, 1.0, 4.0, 1.0, f64::NAN, 0.0];
+        assert_eq!(find_peaks(&gappy, 3.5), vec![3]);
+        assert!(find_peaks(&[0.0, f64::NAN, 4.0, 1.0], 0.0).is_empty());
+    }
+
+    #[test]
+    fn test_score_skewness() {
+        assert!(score_skewness(&[1.0, 2.0, 3.0, 4.0, 5.0]).abs() < 1e-12);
+        assert!(score_skewness(&[1.0, 1.0, 1.0, 2.0, 10.0]) > 0.0);
+        assert!(score_skewness(&[-10.0, -2.0, -1.0, -1.0, -1.0]) < 0.0);
+        assert_eq!(score_skewness(&[]), 0.0);
+        assert_eq!(score_skewness(&[4.0, 4.0]), 0.0);
+    }
+
+    #[test]
+    fn test_score_crest_factor() {
+        assert!((score_crest_factor(&[1.0, -1.0, 1.0, -1.0]) - 1.0).abs() < 1e-12);
+        assert!((score_crest_factor(&[0.0, 0.0, 0.0, 2.0]) - 2.0).abs() < 1e-12);
+        assert_eq!(score_crest_factor(&[]), 0.0);
+        assert_eq!(score_crest_factor(&[0.0, 0.0]), 0.0);
+    }
+
+    #[test]
+    fn test_scores_on_degenerate_input() {
+        for score in [score_signal_to_noise, score_kurtosis, score_skewness, score_crest_factor] {
+            assert_eq!(score(&[]), 0.0);
+            assert_eq!(score(&[0.0, 0.0, 0.0]), 0.0);
+            assert_eq!(score(&[f64::NAN]), 0.0);
+            assert!(score(&[3.0]).is_finite());
+        }
+        assert_eq!(score_kurtosis(&[3.0]), 0.0);
+        assert_eq!(score_signal_to_noise(&[3.0]), 1.0);
+        assert_eq!(score_signal_to_noise(&[-1.0, 1.0]), 0.0);
+    }
+
+    #[test]
+    fn test_scores_skip_nan() {
+        let clean = [1.0, 2.0, 3.0, 10.0];
+        let noisy = [1.0, f64::NAN, 2.0, 3.0, f64::NAN, 10.0];
+        assert_eq!(score_signal_to_noise(&noisy), score_signal_to_noise(&clean));
+        assert_eq!(score_kurtosis(&noisy), score_kurtosis(&clean));
+    }
+}
@@ -0,0 +1,93 @@
+//! Smoothing filters for noisy series such as signature histories or gap offsets.
+//!
+//! Smoothing before resonance detection keeps isolated spikes from masquerading
+//! as structure. Both filters return a series of the same length as their input.
+
+/// Computes the trailing moving average of `data` over `window` points.
+///
+/// Output `i` is the mean of `data[i + 1 - window..=i]`. The first `window - 1`
+/// outputs average only the points seen so far, so a window longer than the data
+/// gives the running mean, and a window of 1 returns the data unchanged. Returns an
+/// empty vector for empty input.
+///
+/// Each window is summed afresh, so a NaN only affects the outputs whose window
+/// contains it, and rounding error does not build up along the series.
+///
+/// # Panics
+/// Panics if `window` is 0.
+pub fn moving_average(data: &[f64], window: usize) -> Vec<f64> {
+    assert!(window > 0, "Window must be at least 1.");
+    (0..data.len())
+        .map(|i| {
+            let points = &data[(i + 1).saturating_sub(window)..=i];
+            points.iter().sum::<f64>() / points.len() as f64
+        })
+        .collect()
+}
+
+/// Computes the exponential moving average of `data` with smoothing factor `alpha`.
+///
+/// The first output equals the first input, and each later output is
+/// `alpha * data[i] + (1 - alpha) * previous`. Smaller `alpha` smooths more heavily;
+/// `alpha == 1.0` returns the data unchanged. Returns an empty vector for empty input.
+///
+/// # Panics
+/// Panics if `alpha` is not in `(0, 1]`.
+pub fn exponential_moving_average(data: &[f64], alpha: f64) -> Vec<f64> {
+    assert!(alpha > 0.0 && alpha <= 1.0, "Smoothing factor must be in (0, 1].");
+    let mut smoothed = Vec::with_capacity(data.len());
+    let mut previous: Option<f64> = None;
+    for &x in data {
+        let value = previous.map_or(x, |prev| alpha * x + (1.0 - alpha) * prev);
+        smoothed.push(value);
+        previous = Some(value);
+    }
+    smoothed
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const STEP: [f64; 6] = [0.0, 0.0, 0.0, 6.0, 6.0, 6.0];
+
+    #[test]
+    fn test_moving_average_of_step() {
+        assert_eq!(moving_average(&STEP, 3), vec![0.0, 0.0, 0.0, 2.0, 4.0, 6.0]);
+        assert_eq!(moving_average(&STEP, 1), STEP.to_vec());
+        // A window longer than the data is the running mean.
+        assert_eq!(moving_average(&[2.0, 4.0, 6.0], 10), vec![2.0, 3.0, 4.0]);
+        assert!(moving_average(&[], 3).is_empty());
+    }
+
+    #[test]
+    fn test_moving_average_nan_stays_local() {
+        let data = [1.0, f64::NAN, 1.0, 1.0, 1.0, 1.0];
+        let smoothed = moving_average(&data, 2);
+        assert_eq!(smoothed[0], 1.0);
+        assert!(smoothed[1].is_nan() && smoothed[2].is_nan());
+        assert_eq!(&smoothed[3..], &[1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_moving_average_does_not_accumulate_error() {
+        // A huge value followed by small ones would leave cancellation error in a running sum.
+        let mut data = vec![1e16];
+        data.extend(std::iter::repeat_n(1.0, 10));
+        let smoothed = moving_average(&data, 2);
+        assert_eq!(&smoothed[2..], &[1.0; 9]);
+    }
+
+    #[test]
+    fn test_exponential_moving_average_of_step() {
+        assert_eq!(exponential_moving_average(&STEP, 0.5), vec![0.0, 0.0, 0.0, 3.0, 4.5, 5.25]);
+        assert_eq!(exponential_moving_average(&STEP, 1.0), STEP.to_vec());
+        assert!(exponential_moving_average(&[], 0.5).is_empty());
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_exponential_moving_average_rejects_zero_alpha() {
+        exponential_moving_average(&STEP, 0.0);
+    }
+}
@@ -37,3 +37,153 @@
                 .sum()
         }
     }
+
+    /// An origin strategy where the origin is the sum of *distinct* prime factor counts
+    /// of all composite numbers in the gap between a prime and its successor.
+    /// `origin(p) = Σ ω(c)` for `c` in `(p, p_next)`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct DistinctCompositeMass;
+    impl OriginStrategy for DistinctCompositeMass {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            let p_next = primes::next_prime(p);
+            (p + 1..p_next)
+                .filter(|&n| !primes::is_prime(n))
+                .map(primes::omega)
+                .sum()
+        }
+    }
+
+    /// An origin strategy where the origin is the sum of divisors of all
+    /// composite numbers in the gap between a prime and its successor.
+    /// `origin(p) = Σ σ(c)` for `c` in `(p, p_next)`.
+    ///
+    /// This weights the gap far more heavily than `CompositeMass`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct DivisorSum;
+    impl OriginStrategy for DivisorSum {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            let p_next = primes::next_prime(p);
+            (p + 1..p_next)
+                .filter(|&n| !primes::is_prime(n))
+                .map(primes::sigma)
+                .sum()
+        }
+    }
+
+    /// An origin strategy based on the primorial of the first `k` primes.
+    /// `origin(p) = primorial(k) mod p`.
+    ///
+    /// The origin is 0 for every prime among the first `k`, since each divides the
+    /// primorial, and moves pseudo-randomly for larger primes. For `p = 0` the
+    /// primorial itself is returned. As with `primes::primorial`, `k` is clamped to
+    /// `primes::MAX_PRIMORIAL_INDEX`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Primorial(pub u64);
+    impl OriginStrategy for Primorial {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            let primorial = primes::primorial(self.0);
+            if p == 0 { return primorial; }
+            primorial % p
+        }
+    }
+
+    /// A combinator strategy whose origin is the sum of two inner strategies' origins.
+    /// `origin(p) = a(p) + b(p)` (wrapping on overflow).
+    ///
+    /// Combinators compose, e.g. `Sum(PrimeGap, Scaled(CompositeMass, 2))`.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Sum<A, B>(pub A, pub B);
+    impl<A: OriginStrategy, B: OriginStrategy> OriginStrategy for Sum<A, B> {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            self.0.calculate_origin(p).wrapping_add(self.1.calculate_origin(p))
+        }
+    }
+
+    /// A combinator strategy that multiplies an inner strategy's origin by a constant.
+    /// `origin(p) = inner(p) * factor` (wrapping on overflow).
+    #[derive(Debug, Clone, Copy)]
+    pub struct Scaled<A>(pub A, pub u64);
+    impl<A: OriginStrategy> OriginStrategy for Scaled<A> {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            self.0.calculate_origin(p).wrapping_mul(self.1)
+        }
+    }
+
+    /// A strategy applying an affine transform to an inner strategy's origin.
+    /// `origin(p) = mul * inner(p) + add` (wrapping on overflow).
+    ///
+    /// Useful for sweeping coefficients without defining new strategy types.
+    #[derive(Debug, Clone, Copy)]
+    pub struct Affine<S: OriginStrategy> {
+        /// The wrapped strategy.
+        pub inner: S,
+        /// The multiplier applied to the inner origin.
+        pub mul: u64,
+        /// The constant added after multiplication.
+        pub add: u64,
+    }
+    impl<S: OriginStrategy> OriginStrategy for Affine<S> {
+        fn calculate_origin(&self, p: u64) -> u64 {
+            self.mul.wrapping_mul(self.inner.calculate_origin(p)).wrapping_add(self.add)
+        }
+    }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_distinct_composite_mass() {
+        // Gap after 23: 24 = 2^3*3, 25 = 5^2, 26 = 2*13, 27 = 3^3, 28 = 2^2*7.
+        assert_eq!(DistinctCompositeMass.calculate_origin(23), 2 + 1 + 2 + 1 + 2);
+        assert_eq!(CompositeMass.calculate_origin(23), 4 + 2 + 2 + 3 + 3);
+        // Twin primes enclose a single composite: 12 = 2^2*3.
+        assert_eq!(DistinctCompositeMass.calculate_origin(11), 2);
+    }
+
+    #[test]
+    fn test_divisor_sum() {
+        // Gap after 7: σ(8) = 15, σ(9) = 13, σ(10) = 18.
+        assert_eq!(DivisorSum.calculate_origin(7), 15 + 13 + 18);
+        // Twin primes enclose a single composite: σ(12) = 28.
+        assert_eq!(DivisorSum.calculate_origin(11), 28);
+    }
+
+    #[test]
+    fn test_primorial_strategy() {
+        // primorial(5) = 2 * 3 * 5 * 7 * 11 = 2310.
+        for p in [2, 3, 5, 7, 11] {
+            assert_eq!(Primorial(5).calculate_origin(p), 0);
+        }
+        assert_eq!(Primorial(5).calculate_origin(13), 2310 % 13);
+        assert_eq!(Primorial(5).calculate_origin(97), 2310 % 97);
+
+        // Past the cap the origin is that of the largest representable primorial.
+        assert_eq!(Primorial(16).calculate_origin(97), Primorial(15).calculate_origin(97));
+
+        let ring = crate::core::MomaRing::new(20, Primorial(5));
+        assert_eq!(ring.signature(13), (13 + 11 + 2310 % 13) % 20);
+    }
+
+    #[test]
+    fn test_combinators_compose() {
+        let combined = Sum(PrimeGap, Scaled(CompositeMass, 2));
+        for p in [3, 13, 23, 89] {
+            let manual = PrimeGap.calculate_origin(p) + 2 * CompositeMass.calculate_origin(p);
+            assert_eq!(combined.calculate_origin(p), manual);
+        }
+        assert_eq!(Sum(Fixed(3), Fixed(4)).calculate_origin(7), 7);
+
+        // PrimeGap(23) = 23 - 19 = 4 and CompositeMass(23) = 14.
+        let ring = crate::core::MomaRing::new(100, combined);
+        assert_eq!(ring.residue(0, 23), 32);
+    }
+
+    #[test]
+    fn test_affine() {
+        let affine = Affine { inner: Fixed(5), mul: 3, add: 1 };
+        assert_eq!(affine.calculate_origin(13), 16);
+        let identity = Affine { inner: PrimeGap, mul: 1, add: 0 };
+        assert_eq!(identity.calculate_origin(23), PrimeGap.calculate_origin(23));
+    }
+}
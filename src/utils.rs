@@ -1,7 +1,7 @@
-//! Utilities for writing data to CSV files.
+//! Utilities for writing data to and reading data from CSV files.
 
 use std::fs::File;
-use std::io::{Write, BufWriter};
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
 //use plotters::prelude::*;
 
 pub fn write_csv(path: &str, data: &[f64]) -> std::io::Result<()> {
@@ -15,3 +15,111 @@ pub fn write_csv(path: &str, data: &[f64]) -> std::io::Result<()> {
     Ok(())
 }
 
+/// Writes multi-column numeric data to a CSV file, preceded by a header row.
+///
+/// # Errors
+/// Returns an `InvalidInput` error, before creating the file, if any row does not
+/// have exactly `headers.len()` entries. I/O errors are propagated.
+pub fn write_csv_columns(path: &str, headers: &[&str], rows: &[Vec<f64>]) -> io::Result<()> {
+    if let Some((i, row)) = rows.iter().enumerate().find(|(_, row)| row.len() != headers.len()) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            format!("row {} has {} columns, expected {}", i, row.len(), headers.len()),
+        ));
+    }
+
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+
+    writeln!(writer, "{}", headers.join(","))?;
+    for row in rows {
+        let line: Vec<String> = row.iter().map(|value| value.to_string()).collect();
+        writeln!(writer, "{}", line.join(","))?;
+    }
+
+    Ok(())
+}
+
+/// Reads back a series written by `write_csv`, returning the values of the second
+/// (`value`) column in file order.
+///
+/// Blank lines are skipped; the index column is not checked.
+///
+/// # Errors
+/// Returns an `InvalidData` error naming the offending line if a non-blank line
+/// has no second column or its value does not parse as an `f64`. I/O errors are
+/// propagated.
+pub fn read_csv(path: &str) -> io::Result<Vec<f64>> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut data = Vec::new();
+
+    for (i, line) in reader.lines().enumerate() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        let value = line
+            .split(',')
+            .nth(1)
+            .and_then(|field| field.trim().parse::<f64>().ok())
+            .ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("malformed line {}: {:?}", i + 1, line))
+            })?;
+        data.push(value);
+    }
+
+    Ok(data)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn temp_path(name: &str) -> String {
+        let path = std::env::temp_dir().join(format!("moma_{}_{}.csv", name, std::process::id()));
+        path.to_string_lossy().into_owned()
+    }
+
+    #[test]
+    fn test_write_csv_columns() {
+        let path = temp_path("columns");
+        let rows = vec![vec![2.0, 0.5], vec![3.0, 1.25]];
+        write_csv_columns(&path, &["prime", "mass"], &rows).unwrap();
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(contents, "prime,mass\n2,0.5\n3,1.25\n");
+    }
+
+    #[test]
+    fn test_write_csv_columns_rejects_ragged_rows() {
+        let path = temp_path("ragged");
+        let rows = vec![vec![2.0, 0.5], vec![3.0]];
+        let err = write_csv_columns(&path, &["prime", "mass"], &rows).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        assert!(fs::metadata(&path).is_err());
+    }
+
+    #[test]
+    fn test_read_csv_round_trips_write_csv() {
+        let path = temp_path("round_trip");
+        let series = [0.0, -1.5, 1.0 / 3.0, 2.5e10];
+        write_csv(&path, &series).unwrap();
+        let restored = read_csv(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(restored.len(), series.len());
+        for (a, b) in restored.iter().zip(series) {
+            assert!((a - b).abs() <= f64::EPSILON * b.abs().max(1.0));
+        }
+    }
+
+    #[test]
+    fn test_read_csv_rejects_malformed_lines() {
+        let path = temp_path("malformed");
+        fs::write(&path, "0,1.0\n1,oops\n").unwrap();
+        let err = read_csv(&path).unwrap_err();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+}